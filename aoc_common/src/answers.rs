@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use crate::{ParseError, Part, non_blank_lines};
+
+/// Accepted answers recorded next to a day's input.
+///
+/// The file holds one `part_one = <answer>` and/or `part_two = <answer>` line;
+/// blank lines and lines starting with `#` are ignored. Answers are kept as
+/// text and compared against the runner's rendered output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    part_one: Option<String>,
+    part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: impl Into<String>) {
+        let slot = match part {
+            Part::One => &mut self.part_one,
+            Part::Two => &mut self.part_two,
+        };
+        *slot = Some(answer.into());
+    }
+
+    /// Parts that have a recorded answer, in order.
+    pub fn parts(&self) -> impl Iterator<Item = Part> + '_ {
+        Part::BOTH.into_iter().filter(|&p| self.get(p).is_some())
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        for (line_no, line) in non_blank_lines(text) {
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::in_line(line_no, line, line, "'part_one = <answer>'"))?;
+            let key = key.trim();
+            let value = value.trim();
+
+            let part = match key {
+                "part_one" => Part::One,
+                "part_two" => Part::Two,
+                _ => {
+                    return Err(ParseError::in_line(
+                        line_no,
+                        line,
+                        key,
+                        "'part_one' or 'part_two'",
+                    ));
+                }
+            };
+            if answers.get(part).is_some() {
+                return Err(ParseError::in_line(
+                    line_no,
+                    line,
+                    key,
+                    "each part recorded once",
+                ));
+            }
+            if value.is_empty() {
+                return Err(ParseError::in_line(line_no, line, value, "an answer"));
+            }
+            answers.set(part, value);
+        }
+
+        Ok(answers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_recorded_answers() {
+        let answers: Answers = "# day 12\npart_one = 510\n\n".parse().unwrap();
+        assert_eq!(answers.get(Part::One), Some("510"));
+        assert_eq!(answers.get(Part::Two), None);
+        assert_eq!(answers.parts().collect::<Vec<_>>(), vec![Part::One]);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let err = "part_one = 1\npart_three = 2"
+            .parse::<Answers>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 1, "part_three", "'part_one' or 'part_two'")
+        );
+
+        let err = "part_two = 1\npart_two = 2".parse::<Answers>().unwrap_err();
+        assert_eq!(err.line, 2);
+
+        assert!("part_one 1".parse::<Answers>().is_err());
+        assert!("part_one =".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt;
+
+/// A parse failure pinned to a position in the puzzle input.
+///
+/// `line` and `column` are 1-based; `column` counts characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text (empty when the input ended early).
+    pub text: String,
+    /// What the parser was looking for, e.g. "a number".
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error for `token` on line `line` (1-based) whose full text is `line_text`.
+    ///
+    /// When `token` is a slice of `line_text` the column points at it; otherwise
+    /// it falls back to the start of the line.
+    pub fn in_line(line: usize, line_text: &str, token: &str, expected: impl Into<String>) -> Self {
+        let column = offset_of(line_text, token)
+            .map(|offset| line_text[..offset].chars().count() + 1)
+            .unwrap_or(1);
+        Self::new(line, column, token, expected)
+    }
+
+    /// Error for `token`, a slice of the whole puzzle `input`; the line and
+    /// column are worked out from where the slice sits.
+    pub fn in_input(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        match offset_of(input, token) {
+            Some(offset) => {
+                let before = &input[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                let line = before.matches('\n').count() + 1;
+                let column = before[line_start..].chars().count() + 1;
+                Self::new(line, column, token, expected)
+            }
+            None => Self::new(1, 1, token, expected),
+        }
+    }
+}
+
+/// Byte offset of `part` inside `whole`, if `part` is a subslice of it.
+fn offset_of(whole: &str, part: &str) -> Option<usize> {
+    let start = whole.as_ptr() as usize;
+    let pos = part.as_ptr() as usize;
+    (pos >= start && pos + part.len() <= start + whole.len()).then(|| pos - start)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "'{}'", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A value that well-formed input pushed past the integer type holding it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// What overflowed, e.g. "the grand total".
+    pub what: String,
+    /// The type it had to fit in.
+    pub ty: &'static str,
+}
+
+impl OverflowError {
+    /// `what` does not fit in a `T`.
+    pub fn new<T>(what: impl Into<String>) -> Self {
+        Self {
+            what: what.into(),
+            ty: type_name::<T>(),
+        }
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not fit in {}", self.what, self.ty)
+    }
+}
+
+impl Error for OverflowError {}
+
+/// Iterate over the lines of `input` paired with their 1-based line numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Like [`numbered_lines`], but skipping lines that are empty or all whitespace.
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    numbered_lines(input).filter(|(_, line)| !line.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_line_points_at_token() {
+        let line = "12,x4,7";
+        let err = ParseError::in_line(3, line, &line[3..5], "a number");
+        assert_eq!(err, ParseError::new(3, 4, "x4", "a number"));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 4: expected a number, found 'x4'"
+        );
+    }
+
+    #[test]
+    fn overflow_names_the_type() {
+        let err = OverflowError::new::<u64>("the password");
+        assert_eq!(err.ty, "u64");
+        assert_eq!(err.to_string(), "the password does not fit in u64");
+    }
+
+    #[test]
+    fn in_line_falls_back_to_line_start() {
+        let err = ParseError::in_line(2, "abc", "", "a direction");
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a direction, found nothing"
+        );
+    }
+
+    #[test]
+    fn in_input_finds_line_and_column() {
+        let input = "1-2,3-4\n5-6,7-x\n";
+        let token = &input[12..15];
+        let err = ParseError::in_input(input, token, "a range");
+        assert_eq!(err, ParseError::new(2, 5, "7-x", "a range"));
+    }
+
+    #[test]
+    fn non_blank_lines_keep_numbering() {
+        let lines: Vec<_> = non_blank_lines("a\n\n  \nb\r\n").collect();
+        assert_eq!(lines, vec![(1, "a"), (4, "b")]);
+    }
+}
@@ -0,0 +1,105 @@
+use std::ops::RangeInclusive;
+
+use crate::Solution;
+
+/// Synthetic puzzle inputs of a chosen size, for benchmarking how a day scales.
+pub trait Generator: Solution {
+    /// Sizes the scaling benchmarks sweep, smallest first. Every size yields an
+    /// input the day solves without error.
+    const SIZES: &'static [usize];
+
+    /// A valid puzzle input of `size` units, where the unit is whatever the
+    /// day's running time grows with (lines, points, grid side, ...). The same
+    /// `size` and `seed` always give the same input.
+    fn generate(size: usize, seed: u64) -> String;
+}
+
+/// Small deterministic random number generator (SplitMix64).
+///
+/// Generated inputs must not change between runs or platforms, so this is
+/// used in place of an external crate whose streams may change across versions.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`. Panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Rng::below called with a zero bound");
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+
+    /// A value in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "Rng::range called with an empty range");
+        match (hi - lo).checked_add(1) {
+            Some(span) => lo + self.below(span),
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a slice of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    /// Fisher-Yates shuffle of `items` in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_stream() {
+        let a: Vec<u64> = std::iter::repeat_with({
+            let mut rng = Rng::new(7);
+            move || rng.next_u64()
+        })
+        .take(4)
+        .collect();
+        let mut rng = Rng::new(7);
+        assert_eq!(a, (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(8).next_u64(), a[0]);
+    }
+
+    #[test]
+    fn stays_in_bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((10..=12).contains(&rng.range(10..=12)));
+            assert!(rng.below(3) < 3);
+        }
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(0..=u64::MAX);
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
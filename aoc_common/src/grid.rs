@@ -0,0 +1,289 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// Row/column offsets of the four orthogonal neighbours.
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Row/column offsets of all eight surrounding cells.
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Positions are `(row, col)` pairs, with `(0, 0)` in the top-left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Build a grid from text rows, converting each character with `cell`.
+    /// Every row must be as wide as the first.
+    pub fn parse_with<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+        mut cell: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let row_width = cells.len() - before;
+
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(ParseError::new(
+                        i + 1,
+                        row_width.min(w) + 1,
+                        line,
+                        format!("a row {} cells wide", w),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn in_bounds(&self, (r, c): (usize, usize)) -> bool {
+        r < self.height && c < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a zero chunk size, so a zero-width grid yields no rows.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `c`, top to bottom.
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        assert!(c < self.width, "column {} out of bounds", c);
+        self.cells.iter().skip(c).step_by(self.width)
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// In-bounds up, left, right and down neighbours of `pos`.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &OFFSETS_4)
+    }
+
+    /// In-bounds neighbours of `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &OFFSETS_8)
+    }
+
+    fn neighbours(
+        &self,
+        (r, c): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let pos = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+            self.in_bounds(pos).then_some(pos)
+        })
+    }
+
+    /// Position of the first cell (row-major) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        let i = self.cells.iter().position(|cell| cell == value)?;
+        Some((i / self.width, i % self.width))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Draw the grid one row per line, using `f` to pick each cell's character.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut f));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Grid<char> {
+    /// Build a character grid from text rows.
+    pub fn parse<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Result<Self, ParseError> {
+        Self::parse_with(lines, |c| c)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        assert!(self.in_bounds((r, c)), "({}, {}) out of bounds", r, c);
+        &self.cells[r * self.width + c]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        assert!(self.in_bounds((r, c)), "({}, {}) out of bounds", r, c);
+        &mut self.cells[r * self.width + c]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(["ab.", ".S.", "..c"]).unwrap()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(0, 1)], 'b');
+        assert_eq!(grid.get((2, 2)), Some(&'c'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), &['.', 'S', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), "..c");
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let err = Grid::parse(["abc", "ab"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "a row 3 cells wide");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn map_and_display() {
+        let grid = sample();
+        assert_eq!(grid.to_string(), "ab.\n.S.\n..c");
+
+        let walls = grid.map(|&c| c != '.');
+        assert_eq!(
+            walls.render(|&w| if w { '#' } else { '.' }),
+            "##.\n.#.\n..#\n"
+        );
+    }
+
+    #[test]
+    fn empty_grid() {
+        let grid = Grid::parse(Vec::<String>::new()).unwrap();
+        assert!(grid.is_empty());
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+    }
+}
@@ -1,40 +1,52 @@
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io;
 use std::num::ParseIntError;
 
+mod answers;
+mod error;
+mod generator;
+mod grid;
+mod numbers;
+mod reader;
+mod result;
+mod sections;
+mod solution;
+mod timing;
+
+pub use answers::Answers;
+pub use error::{OverflowError, ParseError, non_blank_lines, numbered_lines};
+pub use generator::{Generator, Rng};
+pub use grid::Grid;
+pub use numbers::{Integer, extract_integers, parse_integer, split_integers};
+pub use reader::{
+    LineError, LineErrorKind, Lines, LossyLines, LossyReport, Record, Records, open_lines,
+};
+pub use result::{DayResult, results_to_json, write_results_csv};
+pub use sections::{Section, split_sections, split_sections_by_header};
+pub use solution::{Part, Registry, Solution, Solver};
+pub use timing::{Phase, Timings};
+
 /// Read a file into a Vec<String> where each element is a line.
+/// Fails on the first line that cannot be read rather than dropping it.
 pub fn read_file_to_vec(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-
-    let lines: Vec<String> = reader
-        .lines()
-        .filter_map(|line| match line {
-            Ok(l) => Some(l),
-            Err(e) => {
-                eprintln!("Warning: Could not read a line: {}", e);
-                None
-            }
-        })
-        .collect();
-
+    let lines = open_lines(path)?.collect::<Result<Vec<_>, _>>()?;
     Ok(lines)
 }
 
 /// Split a string into leading alphabetic part and trailing integer part.
-/// Returns (alpha_part, number). If no digits are present the number is 0.
-pub fn split_alpha_num(input: &str) -> Result<(String, i32), ParseIntError> {
+/// Returns (alpha_part, number), borrowing the alphabetic part from `input`.
+/// If no digits are present the number is 0; a number too large for `T` is an error.
+pub fn split_alpha_num<T: Integer>(input: &str) -> Result<(&str, T), ParseIntError> {
     let idx = input
         .find(|c: char| c.is_ascii_digit())
         .unwrap_or(input.len());
 
-    let alpha_part = input[..idx].to_string();
+    let alpha_part = &input[..idx];
     let num_part_str = &input[idx..];
 
     let num_part = if num_part_str.is_empty() {
-        0
+        T::default()
     } else {
-        num_part_str.parse::<i32>()?
+        num_part_str.parse::<T>()?
     };
 
     Ok((alpha_part, num_part))
@@ -45,8 +57,9 @@ pub fn read_file_to_string(path: &str) -> std::io::Result<String> {
 }
 
 /// Split `input` by the given delimiter character, trimming whitespace from each piece.
-pub fn split_and_trim(input: &str, delim: char) -> Vec<String> {
-    input.split(delim).map(|s| s.trim().to_string()).collect()
+/// The pieces borrow from `input`; collect them if you need to keep them around.
+pub fn split_and_trim(input: &str, delim: char) -> impl Iterator<Item = &str> {
+    input.split(delim).map(str::trim)
 }
 
 #[cfg(test)]
@@ -56,9 +69,20 @@ mod tests {
 
     #[test]
     fn test_split_alpha_num() {
-        assert_eq!(split_alpha_num("L10").unwrap(), ("L".to_string(), 10));
-        assert_eq!(split_alpha_num("R").unwrap(), ("R".to_string(), 0));
-        assert_eq!(split_alpha_num("FOO123").unwrap(), ("FOO".to_string(), 123));
+        assert_eq!(split_alpha_num("L10").unwrap(), ("L", 10));
+        assert_eq!(split_alpha_num("R").unwrap(), ("R", 0));
+        assert_eq!(split_alpha_num("FOO123").unwrap(), ("FOO", 123));
+        assert!(split_alpha_num::<i32>("R3000000000").is_err());
+        assert_eq!(
+            split_alpha_num::<u64>("R3000000000").unwrap(),
+            ("R", 3_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_split_and_trim() {
+        let pieces: Vec<&str> = split_and_trim(" 1-3 , 5-5,", ',').collect();
+        assert_eq!(pieces, vec!["1-3", "5-5", ""]);
     }
 
     #[test]
@@ -71,4 +95,16 @@ mod tests {
 
         fs::remove_file(path).unwrap();
     }
+
+    #[test]
+    fn test_read_file_to_vec_rejects_bad_utf8() {
+        let path = "bad_utf8.txt";
+        fs::write(path, b"good\n\xc3\x28\nalso good\n").unwrap();
+
+        let err = read_file_to_vec(path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 2"));
+
+        fs::remove_file(path).unwrap();
+    }
 }
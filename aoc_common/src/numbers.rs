@@ -0,0 +1,158 @@
+use std::any::type_name;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A primitive integer type that the number helpers can produce.
+pub trait Integer: FromStr<Err = ParseIntError> + Copy + Default {
+    /// Whether a leading `-` belongs to the number.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal => $($t:ty),*) => {
+        $(impl Integer for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_integer!(true => i8, i16, i32, i64, i128, isize);
+impl_integer!(false => u8, u16, u32, u64, u128, usize);
+
+/// Parse `token`, a slice of `line`, as a `T`.
+///
+/// Errors point at the token and say what was `expected`; a value that does
+/// not fit in `T` reports "`expected` that fits in `T`" instead.
+pub fn parse_integer<T: Integer>(
+    line_no: usize,
+    line: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token.parse::<T>().map_err(|e| {
+        let expected = match e.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                format!("{} that fits in {}", expected, type_name::<T>())
+            }
+            _ => expected.to_string(),
+        };
+        ParseError::in_line(line_no, line, token, expected)
+    })
+}
+
+/// Parse `text`, a slice of `line`, as `delim`-separated integers.
+///
+/// Whitespace around each value is ignored; anything else, including an
+/// empty value between two delimiters, is an error.
+pub fn split_integers<'a, T: Integer>(
+    line_no: usize,
+    line: &'a str,
+    text: &'a str,
+    delim: char,
+    expected: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    text.split(delim)
+        .map(move |token| parse_integer(line_no, line, token.trim(), expected))
+}
+
+/// Every integer in `line`, skipping whatever text lies between them.
+///
+/// For signed `T` a `-` directly before the digits is a sign, unless it
+/// follows a letter or digit (so `1-3` reads as `1` and `3`). The only error
+/// is a value that does not fit in `T`.
+pub fn extract_integers<T: Integer>(
+    line_no: usize,
+    line: &str,
+) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    let bytes = line.as_bytes();
+    let mut pos = 0;
+
+    std::iter::from_fn(move || {
+        while pos < bytes.len() {
+            let starts_negative = T::SIGNED
+                && bytes[pos] == b'-'
+                && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit)
+                && (pos == 0 || !bytes[pos - 1].is_ascii_alphanumeric());
+            if bytes[pos].is_ascii_digit() || starts_negative {
+                let start = pos;
+                pos += 1;
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                return Some(parse_integer(
+                    line_no,
+                    line,
+                    &line[start..pos],
+                    "an integer",
+                ));
+            }
+            pos += 1;
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_signed_and_unsigned() {
+        let line = "p=-3,14 v=2-5";
+        let signed: Vec<i32> = extract_integers(1, line).collect::<Result<_, _>>().unwrap();
+        assert_eq!(signed, vec![-3, 14, 2, 5]);
+
+        let unsigned: Vec<u8> = extract_integers(1, line).collect::<Result<_, _>>().unwrap();
+        assert_eq!(unsigned, vec![3, 14, 2, 5]);
+
+        assert_eq!(extract_integers::<i64>(1, "no numbers").count(), 0);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let err = extract_integers::<u8>(4, "1,300")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(4, 3, "300", "an integer that fits in u8")
+        );
+
+        let big: Vec<i128> = extract_integers(1, "170141183460469231731687303715884105727")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(big, vec![i128::MAX]);
+    }
+
+    #[test]
+    fn overflow_names_the_type() {
+        let err = parse_integer::<u8>(4, "1,300", &"1,300"[2..], "an integer").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(4, 3, "300", "an integer that fits in u8")
+        );
+
+        let line = "170141183460469231731687303715884105727";
+        assert_eq!(
+            parse_integer::<i128>(1, line, line, "an integer"),
+            Ok(i128::MAX)
+        );
+    }
+
+    #[test]
+    fn split_integers_is_strict() {
+        let line = "{3, 5,4}";
+        let values: Vec<u32> = split_integers(1, line, &line[1..7], ',', "a target")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(values, vec![3, 5, 4]);
+
+        let line = "1,,x";
+        let mut it = split_integers::<i32>(2, line, line, ',', "a value");
+        assert_eq!(it.next(), Some(Ok(1)));
+        assert_eq!(it.next(), Some(Err(ParseError::new(2, 3, "", "a value"))));
+        assert_eq!(it.next(), Some(Err(ParseError::new(2, 4, "x", "a value"))));
+    }
+}
@@ -0,0 +1,338 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+/// Why a line could not be read.
+#[derive(Debug)]
+pub enum LineErrorKind {
+    /// The line is not valid UTF-8.
+    InvalidUtf8,
+    /// The underlying reader failed, e.g. the input was truncated.
+    Io(io::Error),
+}
+
+/// A line that could not be read, with its 1-based line number.
+#[derive(Debug)]
+pub struct LineError {
+    pub line: usize,
+    pub kind: LineErrorKind,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LineErrorKind::InvalidUtf8 => write!(f, "line {}: not valid UTF-8", self.line),
+            LineErrorKind::Io(e) => write!(f, "line {}: {}", self.line, e),
+        }
+    }
+}
+
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            LineErrorKind::Io(e) => Some(e),
+            LineErrorKind::InvalidUtf8 => None,
+        }
+    }
+}
+
+impl From<LineError> for io::Error {
+    fn from(e: LineError) -> Self {
+        match e.kind {
+            LineErrorKind::Io(ref io) => io::Error::new(io.kind(), e.to_string()),
+            LineErrorKind::InvalidUtf8 => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        }
+    }
+}
+
+/// Read the next raw line (without its `\n` or `\r\n`) into `buf`.
+/// Returns `Ok(false)` at end of input.
+fn next_raw_line<R: BufRead>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<bool> {
+    buf.clear();
+    if reader.read_until(b'\n', buf)? == 0 {
+        return Ok(false);
+    }
+    if buf.last() == Some(&b'\n') {
+        buf.pop();
+        if buf.last() == Some(&b'\r') {
+            buf.pop();
+        }
+    }
+    Ok(true)
+}
+
+/// Strict streaming line reader: yields each line, or an error for the first
+/// line that cannot be read, after which it stops.
+pub struct Lines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            line: 0,
+            done: false,
+        }
+    }
+
+    /// Switch to lossy mode, which never fails on bad lines.
+    pub fn lossy(self) -> LossyLines<R> {
+        LossyLines::new(self.reader)
+    }
+
+    /// Group the remaining lines into blank-line-separated records.
+    pub fn records(self) -> Records<Self> {
+        Records::new(self)
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<String, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.line += 1;
+
+        let result = match next_raw_line(&mut self.reader, &mut self.buf) {
+            Ok(false) => {
+                self.done = true;
+                return None;
+            }
+            Ok(true) => String::from_utf8(std::mem::take(&mut self.buf))
+                .map_err(|_| LineErrorKind::InvalidUtf8),
+            Err(e) => Err(LineErrorKind::Io(e)),
+        };
+
+        Some(result.map_err(|kind| {
+            self.done = true;
+            LineError {
+                line: self.line,
+                kind,
+            }
+        }))
+    }
+}
+
+/// What a [`LossyLines`] reader had to paper over.
+#[derive(Debug, Default)]
+pub struct LossyReport {
+    /// Lines yielded so far.
+    pub lines: usize,
+    /// Lines that contained invalid UTF-8 and were decoded with replacement characters.
+    pub replaced: usize,
+    /// The read error that ended the input early, if any.
+    pub io_error: Option<LineError>,
+}
+
+impl LossyReport {
+    pub fn is_clean(&self) -> bool {
+        self.replaced == 0 && self.io_error.is_none()
+    }
+}
+
+/// Lossy streaming line reader: invalid UTF-8 is replaced with `U+FFFD`
+/// and a read error ends the input. Both are counted in [`LossyLines::report`]
+/// so callers can tell whether the answer might be affected.
+pub struct LossyLines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    report: LossyReport,
+    done: bool,
+}
+
+impl<R: BufRead> LossyLines<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            report: LossyReport::default(),
+            done: false,
+        }
+    }
+
+    pub fn report(&self) -> &LossyReport {
+        &self.report
+    }
+
+    pub fn into_report(self) -> LossyReport {
+        self.report
+    }
+}
+
+impl<R: BufRead> Iterator for LossyLines<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+
+        match next_raw_line(&mut self.reader, &mut self.buf) {
+            Ok(true) => {
+                self.report.lines += 1;
+                let line = match String::from_utf8(std::mem::take(&mut self.buf)) {
+                    Ok(line) => line,
+                    Err(e) => {
+                        self.report.replaced += 1;
+                        String::from_utf8_lossy(e.as_bytes()).into_owned()
+                    }
+                };
+                Some(line)
+            }
+            Ok(false) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                self.report.io_error = Some(LineError {
+                    line: self.report.lines + 1,
+                    kind: LineErrorKind::Io(e),
+                });
+                None
+            }
+        }
+    }
+}
+
+/// A run of non-blank lines from the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// 1-based line number of the record's first line.
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+/// Groups lines into [`Record`]s separated by one or more blank lines.
+pub struct Records<I> {
+    lines: I,
+    line: usize,
+}
+
+impl<I> Records<I>
+where
+    I: Iterator<Item = Result<String, LineError>>,
+{
+    pub fn new(lines: I) -> Self {
+        Self { lines, line: 0 }
+    }
+}
+
+impl<I> Iterator for Records<I>
+where
+    I: Iterator<Item = Result<String, LineError>>,
+{
+    type Item = Result<Record, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record: Option<Record> = None;
+
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if line.trim().is_empty() {
+                if record.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            record
+                .get_or_insert_with(|| Record {
+                    first_line: self.line,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+
+        record.map(Ok)
+    }
+}
+
+/// Open `path` for strict streaming line-by-line reading.
+pub fn open_lines(path: &str) -> io::Result<Lines<BufReader<File>>> {
+    Ok(Lines::new(BufReader::new(File::open(path)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn strict_stops_at_first_bad_line() {
+        let input: &[u8] = b"ok\r\nbad \xff\nnever\n";
+        let mut lines = Lines::new(Cursor::new(input));
+
+        assert_eq!(lines.next().unwrap().unwrap(), "ok");
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(err.line, 2);
+        assert!(matches!(err.kind, LineErrorKind::InvalidUtf8));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn lossy_counts_replacements() {
+        let input: &[u8] = b"a\nb\xffc\nd";
+        let mut lines = Lines::new(Cursor::new(input)).lossy();
+
+        let read: Vec<String> = lines.by_ref().collect();
+        assert_eq!(read, vec!["a", "b\u{fffd}c", "d"]);
+
+        let report = lines.into_report();
+        assert_eq!((report.lines, report.replaced), (3, 1));
+        assert!(!report.is_clean());
+    }
+
+    /// A reader that fails after handing out its data, like a dropped connection.
+    struct Truncated<'a>(&'a [u8]);
+
+    impl Read for Truncated<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated"));
+            }
+            self.0.read(buf)
+        }
+    }
+
+    #[test]
+    fn read_errors_are_reported() {
+        let strict: Vec<_> = Lines::new(BufReader::new(Truncated(b"one\ntw"))).collect();
+        assert_eq!(strict.len(), 2);
+        assert_eq!(strict[0].as_ref().unwrap(), "one");
+        assert_eq!(strict[1].as_ref().unwrap_err().line, 2);
+
+        let mut lossy = LossyLines::new(BufReader::new(Truncated(b"one\ntw")));
+        assert_eq!(lossy.by_ref().count(), 1);
+        assert_eq!(lossy.report().io_error.as_ref().unwrap().line, 2);
+    }
+
+    #[test]
+    fn records_split_on_blank_lines() {
+        let input = "0:\n##\n\n\n1:\n#.\n  \n4x4: 1 2\n";
+        let records: Vec<Record> = Lines::new(Cursor::new(input))
+            .records()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].lines, vec!["0:", "##"]);
+        assert_eq!(records[1].first_line, 5);
+        assert_eq!(records[2].first_line, 8);
+        assert_eq!(records[2].lines, vec!["4x4: 1 2"]);
+    }
+}
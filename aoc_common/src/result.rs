@@ -0,0 +1,121 @@
+use std::io;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use crate::Part;
+
+/// The outcome of answering one part of one day, in a form dashboards can ingest.
+///
+/// Serializes with the fields `day`, `part`, `answer`, `elapsed_ns`,
+/// `parse_ns` and `error`; exactly one of `answer` and `error` is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    /// Time spent answering this part, not counting the shared parse.
+    #[serde(rename = "elapsed_ns", serialize_with = "as_nanos")]
+    pub elapsed: Duration,
+    /// Time spent parsing the day's input, shared by all of its parts.
+    #[serde(rename = "parse_ns", serialize_with = "as_nanos")]
+    pub parse_elapsed: Duration,
+    pub error: Option<String>,
+}
+
+impl DayResult {
+    pub fn ok(day: u8, part: Part, answer: impl Into<String>, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer: Some(answer.into()),
+            elapsed,
+            parse_elapsed: Duration::ZERO,
+            error: None,
+        }
+    }
+
+    /// A failed part; the error is rendered with its full context chain.
+    pub fn failed(day: u8, part: Part, error: &anyhow::Error, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            elapsed,
+            parse_elapsed: Duration::ZERO,
+            error: Some(format!("{:#}", error)),
+        }
+    }
+
+    pub fn with_parse_elapsed(mut self, parse_elapsed: Duration) -> Self {
+        self.parse_elapsed = parse_elapsed;
+        self
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+fn as_nanos<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX))
+}
+
+/// Render `results` as a pretty-printed JSON array.
+pub fn results_to_json(results: &[DayResult]) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(results)
+}
+
+/// Write `results` as CSV with a header row.
+pub fn write_results_csv(results: &[DayResult], writer: impl io::Write) -> csv::Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    for result in results {
+        csv.serialize(result)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn sample() -> Vec<DayResult> {
+        vec![
+            DayResult::ok(7, Part::One, "1649", Duration::from_micros(12))
+                .with_parse_elapsed(Duration::from_micros(3)),
+            DayResult::failed(
+                7,
+                Part::Two,
+                &anyhow!("bad, \"quoted\" input"),
+                Duration::ZERO,
+            ),
+        ]
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&results_to_json(&sample()).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"day": 7, "part": 1, "answer": "1649", "elapsed_ns": 12000, "parse_ns": 3000, "error": null},
+                {"day": 7, "part": 2, "answer": null, "elapsed_ns": 0, "parse_ns": 0, "error": "bad, \"quoted\" input"},
+            ])
+        );
+    }
+
+    #[test]
+    fn serializes_to_csv() {
+        let mut out = Vec::new();
+        write_results_csv(&sample(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,part,answer,elapsed_ns,parse_ns,error\n\
+             7,1,1649,12000,3000,\n\
+             7,2,,0,0,\"bad, \"\"quoted\"\" input\"\n"
+        );
+    }
+}
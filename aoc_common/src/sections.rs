@@ -0,0 +1,144 @@
+/// A block of puzzle input borrowed from the original text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// Position among the sections, starting at 0.
+    pub index: usize,
+    /// The header line that opened the section, without a trailing `:`.
+    /// Always `None` for sections split only by blank lines.
+    pub name: Option<&'a str>,
+    /// 1-based line number of the first body line.
+    pub first_line: usize,
+    /// The section's lines, excluding the header and surrounding blank lines.
+    pub body: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.body.lines()
+    }
+
+    /// Body lines paired with their 1-based line numbers in the full input.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + use<'a> {
+        let first_line = self.first_line;
+        self.body
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (first_line + i, line))
+    }
+}
+
+/// Split `input` into sections separated by one or more blank lines.
+pub fn split_sections(input: &str) -> Vec<Section<'_>> {
+    split_sections_by_header(input, |_| false)
+}
+
+/// Split `input` into sections, starting a new one at every line matching
+/// `is_header` as well as after blank lines. A header names its section and
+/// is not part of the body; lines before the first header form an unnamed section.
+pub fn split_sections_by_header<'a>(
+    input: &'a str,
+    mut is_header: impl FnMut(&str) -> bool,
+) -> Vec<Section<'a>> {
+    struct Open<'a> {
+        name: Option<&'a str>,
+        first_line: usize,
+        start: usize,
+        end: usize,
+    }
+
+    let mut sections = Vec::new();
+    let mut close = |open: Option<Open<'a>>| {
+        if let Some(open) = open {
+            sections.push(Section {
+                index: sections.len(),
+                name: open.name,
+                first_line: open.first_line,
+                body: &input[open.start..open.end],
+            });
+        }
+    };
+
+    let mut current: Option<Open<'a>> = None;
+    let mut offset = 0;
+    for (i, raw) in input.split_inclusive('\n').enumerate() {
+        let line_no = i + 1;
+        let start = offset;
+        offset += raw.len();
+        let line = raw.strip_suffix('\n').unwrap_or(raw);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        if line.trim().is_empty() {
+            close(current.take());
+        } else if is_header(line) {
+            close(current.take());
+            let name = line.trim();
+            current = Some(Open {
+                name: Some(name.strip_suffix(':').unwrap_or(name)),
+                first_line: line_no + 1,
+                start: offset,
+                end: offset,
+            });
+        } else {
+            let open = current.get_or_insert(Open {
+                name: None,
+                first_line: line_no,
+                start,
+                end: start,
+            });
+            open.end = start + line.len();
+        }
+    }
+    close(current);
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blank_lines_separate_sections() {
+        let input = "1-3\n5-5\n\n\n2\n5\r\n";
+        let sections = split_sections(input);
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].body, "1-3\n5-5");
+        assert_eq!(sections[1].index, 1);
+        assert_eq!(sections[1].first_line, 5);
+        assert_eq!(sections[1].lines().collect::<Vec<_>>(), vec!["2", "5"]);
+        assert_eq!(
+            sections[1].numbered_lines().collect::<Vec<_>>(),
+            vec![(5, "2"), (6, "5")]
+        );
+        assert!(sections.iter().all(|s| s.name.is_none()));
+    }
+
+    #[test]
+    fn headers_name_sections() {
+        let input = "intro\n0:\n##\n1:\n#.\n.#\n\n4x4: 1 2\n";
+        let sections = split_sections_by_header(input, |l| l.ends_with(':'));
+
+        let names: Vec<_> = sections.iter().map(|s| s.name).collect();
+        assert_eq!(names, vec![None, Some("0"), Some("1"), None]);
+        assert_eq!(sections[0].body, "intro");
+        assert_eq!(sections[2].body, "#.\n.#");
+        assert_eq!(sections[2].first_line, 5);
+        assert_eq!(sections[3].body, "4x4: 1 2");
+    }
+
+    #[test]
+    fn header_without_body() {
+        let sections = split_sections_by_header("a:\nb:\nx", |l| l.ends_with(':'));
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].body, "");
+        assert_eq!(sections[1].body, "x");
+        assert_eq!(sections[1].first_line, 3);
+    }
+
+    #[test]
+    fn empty_input_has_no_sections() {
+        assert!(split_sections("").is_empty());
+        assert!(split_sections("\n \n").is_empty());
+    }
+}
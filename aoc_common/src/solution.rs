@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Serialize, Serializer};
+
+use crate::DayResult;
+
+/// Which half of a day's puzzle to answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Serialized as the number `1` or `2`, matching [`Display`].
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            other => Err(anyhow!("invalid part '{}', expected 1 or 2", other)),
+        }
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer each part from the parsed form.
+pub trait Solution {
+    /// Puzzle day number, 1 to 12.
+    const DAY: u8;
+    /// Whether the puzzle has a second part (the final day does not).
+    const HAS_PART_TWO: bool = true;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+/// Object-safe view of a [`Solution`] with answers rendered as strings, so
+/// solvers for different days can live side by side in a [`Registry`].
+pub trait Solver: Send + Sync {
+    fn day(&self) -> u8;
+
+    /// The parts this day can answer.
+    fn parts(&self) -> &'static [Part];
+
+    /// Parse `input` once and answer each of `parts` in order.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<String>>;
+
+    /// Like [`Solver::solve`], but never fails: each part gets a [`DayResult`]
+    /// carrying its answer or error, the time to answer it, and the time
+    /// spent on the shared parse.
+    fn run(&self, input: &str, parts: &[Part]) -> Vec<DayResult>;
+}
+
+struct Day<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Solver for Day<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        if S::HAS_PART_TWO {
+            &Part::BOTH
+        } else {
+            &Part::BOTH[..1]
+        }
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<String>> {
+        if !S::HAS_PART_TWO && parts.contains(&Part::Two) {
+            bail!("day {} has no part two", S::DAY);
+        }
+
+        let parsed = S::parse(input)?;
+        parts
+            .iter()
+            .map(|&part| answer::<S>(&parsed, part))
+            .collect()
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Vec<DayResult> {
+        let start = Instant::now();
+        let parsed = S::parse(input);
+        let parse_time = start.elapsed();
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let e = e.context("failed to parse input");
+                return parts
+                    .iter()
+                    .map(|&part| {
+                        DayResult::failed(S::DAY, part, &e, Duration::ZERO)
+                            .with_parse_elapsed(parse_time)
+                    })
+                    .collect();
+            }
+        };
+
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = if part == Part::Two && !S::HAS_PART_TWO {
+                    Err(anyhow!("day {} has no part two", S::DAY))
+                } else {
+                    answer::<S>(&parsed, part)
+                };
+                let result = match answer {
+                    Ok(answer) => DayResult::ok(S::DAY, part, answer, start.elapsed()),
+                    Err(e) => DayResult::failed(S::DAY, part, &e, start.elapsed()),
+                };
+                result.with_parse_elapsed(parse_time)
+            })
+            .collect()
+    }
+}
+
+fn answer<S: Solution>(parsed: &S::Input, part: Part) -> Result<String> {
+    match part {
+        Part::One => S::part_one(parsed).map(|a| a.to_string()),
+        Part::Two => S::part_two(parsed).map(|a| a.to_string()),
+    }
+}
+
+/// Lookup table from day number to that day's solver.
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<u8, Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `S` to the registry, replacing any solver already registered for its day.
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        self.solvers.insert(S::DAY, Box::new(Day::<S>(PhantomData)));
+        self
+    }
+
+    /// Builder-style variant of [`Registry::register`].
+    pub fn with<S: Solution + 'static>(mut self) -> Self {
+        self.register::<S>();
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn Solver> {
+        self.solvers.get(&day).map(|s| s.as_ref())
+    }
+
+    /// Registered solvers in ascending day order.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.values().map(|s| s.as_ref())
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.solvers.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.solvers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solvers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u8 = 3;
+        type Input = Vec<usize>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(str::len).collect())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<usize> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<usize> {
+            input.iter().max().copied().ok_or_else(|| anyhow!("empty"))
+        }
+    }
+
+    struct OnePart;
+
+    impl Solution for OnePart {
+        const DAY: u8 = 12;
+        const HAS_PART_TWO: bool = false;
+        type Input = ();
+        type PartOne = &'static str;
+        type PartTwo = &'static str;
+
+        fn parse(_: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part_one(_: &()) -> Result<&'static str> {
+            Ok("done")
+        }
+
+        fn part_two(_: &()) -> Result<&'static str> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn registry_looks_up_by_day() {
+        let registry = Registry::new().with::<OnePart>().with::<Lengths>();
+
+        assert_eq!(registry.days().collect::<Vec<_>>(), vec![3, 12]);
+        assert!(registry.get(1).is_none());
+
+        let solver = registry.get(3).unwrap();
+        assert_eq!(solver.parts(), &[Part::One, Part::Two]);
+        assert_eq!(
+            solver.solve("ab\nabcd\n", &Part::BOTH).unwrap(),
+            vec!["6".to_string(), "4".to_string()]
+        );
+        assert!(solver.solve("", &[Part::Two]).is_err());
+    }
+
+    #[test]
+    fn single_part_day_rejects_part_two() {
+        let registry = Registry::new().with::<OnePart>();
+        let solver = registry.get(12).unwrap();
+
+        assert_eq!(solver.parts(), &[Part::One]);
+        assert_eq!(solver.solve("", &[Part::One]).unwrap(), vec!["done"]);
+        assert!(solver.solve("", &[Part::Two]).is_err());
+    }
+
+    #[test]
+    fn run_reports_each_part() {
+        let registry = Registry::new().with::<OnePart>().with::<Lengths>();
+
+        let results = registry.get(3).unwrap().run("", &Part::BOTH);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer.as_deref(), Some("0"));
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].error.as_deref(), Some("empty"));
+
+        let results = registry.get(12).unwrap().run("", &Part::BOTH);
+        assert_eq!(results[0].answer.as_deref(), Some("done"));
+        assert_eq!((results[1].day, results[1].part), (12, Part::Two));
+        assert!(!results[1].is_ok());
+    }
+
+    #[test]
+    fn part_from_str() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("two".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
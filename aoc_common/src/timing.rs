@@ -0,0 +1,128 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::{DayResult, Part};
+
+/// One separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::PartOne, Phase::PartTwo];
+
+    /// Stable identifier, used for benchmark ids and report columns.
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::PartOne => "part_one",
+            Phase::PartTwo => "part_two",
+        }
+    }
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::PartOne,
+            Part::Two => Phase::PartTwo,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Where one day's time went. Parts that were not run are `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Timings {
+    /// Gather the phase timings from one day's results.
+    pub fn from_results(results: &[DayResult]) -> Self {
+        let mut timings = Timings::default();
+        for result in results {
+            timings.parse = timings.parse.max(result.parse_elapsed);
+            match result.part {
+                Part::One => timings.part_one = Some(result.elapsed),
+                Part::Two => timings.part_two = Some(result.elapsed),
+            }
+        }
+        timings
+    }
+
+    pub fn get(&self, phase: Phase) -> Option<Duration> {
+        match phase {
+            Phase::Parse => Some(self.parse),
+            Phase::PartOne => self.part_one,
+            Phase::PartTwo => self.part_two,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        Phase::ALL.iter().filter_map(|&p| self.get(p)).sum()
+    }
+}
+
+/// `parse 1.20ms, part_one 35.00µs, part_two 2.10ms, total 3.34ms`
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for phase in Phase::ALL {
+            if let Some(elapsed) = self.get(phase) {
+                write!(f, "{} {:.2?}, ", phase, elapsed)?;
+            }
+        }
+        write!(f, "total {:.2?}", self.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_phases_from_results() {
+        let results = vec![
+            DayResult::ok(5, Part::One, "701", Duration::from_millis(2))
+                .with_parse_elapsed(Duration::from_millis(10)),
+            DayResult::ok(5, Part::Two, "3", Duration::from_millis(5))
+                .with_parse_elapsed(Duration::from_millis(10)),
+        ];
+        let timings = Timings::from_results(&results);
+
+        assert_eq!(timings.parse, Duration::from_millis(10));
+        assert_eq!(timings.get(Phase::PartTwo), Some(Duration::from_millis(5)));
+        assert_eq!(timings.total(), Duration::from_millis(17));
+        assert_eq!(
+            timings.to_string(),
+            "parse 10.00ms, part_one 2.00ms, part_two 5.00ms, total 17.00ms"
+        );
+    }
+
+    #[test]
+    fn missing_parts_are_skipped() {
+        let results = [DayResult::ok(
+            12,
+            Part::One,
+            "510",
+            Duration::from_micros(3),
+        )];
+        let timings = Timings::from_results(&results);
+
+        assert_eq!(timings.part_two, None);
+        assert_eq!(
+            timings.to_string(),
+            "parse 0.00ns, part_one 3.00µs, total 3.00µs"
+        );
+    }
+}
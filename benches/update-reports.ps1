@@ -20,12 +20,20 @@ if (Test-Path "..\target\criterion") {
     
     for ($i = 1; $i -le 12; $i++) {
         $dayName = "day_{0:d2}" -f $i
-        $estimatesFile = "..\target\criterion\$dayName\base\estimates.json"
+
+        # A full run is the parse plus each part; day 12 has no part two
+        $meanNs = 0
+        $found = $false
+        foreach ($phase in @("parse", "part_one", "part_two")) {
+            $estimatesFile = "..\target\criterion\$dayName\$phase\base\estimates.json"
+            if (Test-Path $estimatesFile) {
+                $json = Get-Content $estimatesFile | ConvertFrom-Json
+                $meanNs += $json.mean.point_estimate
+                $found = $true
+            }
+        }
         
-        if (Test-Path $estimatesFile) {
-            $json = Get-Content $estimatesFile | ConvertFrom-Json
-            $meanNs = $json.mean.point_estimate
-            
+        if ($found) {
             # Convert to appropriate unit
             if ($meanNs -lt 1000) {
                 $timeStr = "{0:N0} ns" -f $meanNs
@@ -0,0 +1,20 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayEight;
+
+impl Generator for DayEight {
+    // Part one makes 1000 connections and needs three circuits left over,
+    // so fewer than about 1000 boxes is not a valid puzzle.
+    const SIZES: &'static [usize] = &[1_000, 1_500, 2_000, 3_000];
+
+    /// `size` junction boxes scattered uniformly through a 100000-wide cube.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::with_capacity(size * 18);
+        for _ in 0..size {
+            let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+            input.push_str(&format!("{},{},{}\n", x, y, z));
+        }
+        input
+    }
+}
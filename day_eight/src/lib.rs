@@ -1,36 +1,104 @@
 mod dsu;
+mod generator;
+
+use anyhow::{Result, anyhow};
+use aoc_common::{ParseError, Solution, non_blank_lines, parse_integer};
 
 pub use dsu::{DisjointSetUnion, Point};
 
+/// Number of shortest connections made before measuring circuits in part one.
+pub const MERGE_LIMIT: usize = 1000;
+
+type Prepared = (Vec<(f64, usize, usize)>, Vec<i128>, usize);
+
+pub struct DayEight;
+
+impl Solution for DayEight {
+    const DAY: u8 = 8;
+    type Input = Prepared;
+    type PartOne = usize;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(prepare(input)?)
+    }
+
+    fn part_one((pairs, _, n): &Self::Input) -> Result<usize> {
+        prod_of_top_three(pairs, *n, MERGE_LIMIT).ok_or_else(|| {
+            anyhow!(
+                "fewer than three circuits after {} connections",
+                MERGE_LIMIT
+            )
+        })
+    }
+
+    fn part_two((pairs, xs, n): &Self::Input) -> Result<i128> {
+        prod_of_last_con_x(pairs, xs, *n)
+            .ok_or_else(|| anyhow!("junction boxes never form a single circuit"))
+    }
+}
+
 pub fn distance(a: &Point, b: &Point) -> f64 {
     ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
 }
 
-pub fn solve(lines: &[String], merge_limit: usize) -> (Option<usize>, Option<i128>) {
-    let (pairs, xs, n) = prepare(lines);
+pub fn solve(input: &str, merge_limit: usize) -> Result<(Option<usize>, Option<i128>), ParseError> {
+    let (pairs, xs, n) = prepare(input)?;
     if n == 0 {
-        return (None, None);
+        return Ok((None, None));
     }
 
     let part1_solution = prod_of_top_three(&pairs, n, merge_limit);
     let part2_solution = prod_of_last_con_x(&pairs, &xs, n);
-    (part1_solution, part2_solution)
+    Ok((part1_solution, part2_solution))
+}
+
+/// Parse one `X,Y,Z` line into its coordinates.
+///
+/// Coordinates are read as `i64` so the product of two X coordinates in
+/// part two always fits in an `i128`.
+fn parse_coords(line_no: usize, line: &str) -> Result<[i128; 3], ParseError> {
+    let mut coords = [0i128; 3];
+    let mut parts = line.split(',');
+    for (axis, coord) in ["X", "Y", "Z"].iter().zip(coords.iter_mut()) {
+        let part = parts.next().ok_or_else(|| {
+            ParseError::in_line(
+                line_no,
+                line,
+                &line[line.len()..],
+                format!("a {} coordinate", axis),
+            )
+        })?;
+        *coord = parse_integer::<i64>(
+            line_no,
+            line,
+            part.trim(),
+            &format!("a {} coordinate", axis),
+        )?
+        .into();
+    }
+    if let Some(extra) = parts.next() {
+        return Err(ParseError::in_line(
+            line_no,
+            line,
+            extra,
+            "end of line after Z",
+        ));
+    }
+    Ok(coords)
 }
 
 /// Parse lines and prepare sorted distance pairs and xs vector
-pub fn prepare(lines: &[String]) -> (Vec<(f64, usize, usize)>, Vec<i128>, usize) {
+pub fn prepare(input: &str) -> Result<Prepared, ParseError> {
     let mut points: Vec<Point> = Vec::new();
     let mut xs: Vec<i128> = Vec::new();
-    for l in lines {
-        let nums_int: Vec<i128> = l
-            .split(',')
-            .map(|n| n.trim().parse::<i128>().unwrap())
-            .collect();
-        xs.push(nums_int[0]);
+    for (line_no, l) in non_blank_lines(input) {
+        let [x, y, z] = parse_coords(line_no, l)?;
+        xs.push(x);
         points.push(Point {
-            x: nums_int[0] as f64,
-            y: nums_int[1] as f64,
-            z: nums_int[2] as f64,
+            x: x as f64,
+            y: y as f64,
+            z: z as f64,
         });
     }
 
@@ -42,7 +110,7 @@ pub fn prepare(lines: &[String]) -> (Vec<(f64, usize, usize)>, Vec<i128>, usize)
         }
     }
     pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-    (pairs, xs, n)
+    Ok((pairs, xs, n))
 }
 
 pub fn prod_of_top_three(
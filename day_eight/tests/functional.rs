@@ -1,16 +1,23 @@
 use aoc_common as common;
 use day_eight as lib;
 
+/// The integers on one `X,Y,Z` line.
+fn coords(line: &str) -> Vec<i128> {
+    common::extract_integers(1, line)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
 #[test]
 fn example_counts_and_last_pair() {
     let lines = common::read_file_to_vec("example.txt").unwrap();
     let mut points: Vec<lib::Point> = Vec::new();
     for l in &lines {
-        let nums: Vec<f64> = l.split(',').map(|n| n.trim().parse().unwrap()).collect();
+        let nums = coords(l);
         points.push(lib::Point {
-            x: nums[0],
-            y: nums[1],
-            z: nums[2],
+            x: nums[0] as f64,
+            y: nums[1] as f64,
+            z: nums[2] as f64,
         });
     }
 
@@ -54,10 +61,7 @@ fn example_counts_and_last_pair() {
         }
     }
     let (i, j) = last.expect("should have merged to one component");
-    let xs: Vec<i128> = lines
-        .iter()
-        .map(|l| l.split(',').next().unwrap().trim().parse().unwrap())
-        .collect();
+    let xs: Vec<i128> = lines.iter().map(|l| coords(l)[0]).collect();
     assert_eq!(xs[i] * xs[j], 25272i128);
 }
 
@@ -67,7 +71,7 @@ fn puzzle_input_results() {
     let mut points: Vec<lib::Point> = Vec::new();
     let mut xs: Vec<i128> = Vec::new();
     for l in &lines {
-        let nums_int: Vec<i128> = l.split(',').map(|n| n.trim().parse().unwrap()).collect();
+        let nums_int = coords(l);
         xs.push(nums_int[0]);
         points.push(lib::Point {
             x: nums_int[0] as f64,
@@ -117,3 +121,20 @@ fn puzzle_input_results() {
     let (i, j) = last.expect("should have merged to one component");
     assert_eq!(xs[i] * xs[j], 42047840i128);
 }
+
+#[test]
+fn malformed_lines_are_errors() {
+    let err = lib::prepare("1,2,3\n4,5\n").unwrap_err();
+    assert_eq!(err, common::ParseError::new(2, 4, "", "a Z coordinate"));
+
+    assert!(lib::solve("1,2,3,4", 10).is_err());
+
+    let err = lib::prepare("1,y,3").unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (3, "y"));
+
+    let err = lib::prepare("0,0,0\n9223372036854775808,0,0").unwrap_err();
+    assert_eq!(err.expected, "a X coordinate that fits in i64");
+
+    let (_, last) = lib::solve("-9223372036854775808,0,0\n-9223372036854775808,1,0", 1).unwrap();
+    assert_eq!(last, Some(1i128 << 126));
+}
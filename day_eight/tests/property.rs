@@ -0,0 +1,68 @@
+use proptest::prelude::*;
+
+/// Every pair of boxes, shortest first, ties kept in `(i, j)` order.
+fn sorted_pairs(points: &[[i64; 3]]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let d: i64 = (0..3).map(|k| (points[i][k] - points[j][k]).pow(2)).sum();
+            pairs.push((d, i, j));
+        }
+    }
+    pairs.sort_by_key(|&(d, _, _)| d);
+    pairs.into_iter().map(|(_, i, j)| (i, j)).collect()
+}
+
+/// Circuit sizes after wiring `edges`, found by a search from each box.
+fn circuit_sizes(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut seen = vec![false; n];
+    let mut sizes = Vec::new();
+    for start in 0..n {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut stack = vec![start];
+        let mut size = 0;
+        while let Some(b) = stack.pop() {
+            size += 1;
+            for &(i, j) in edges {
+                for (from, to) in [(i, j), (j, i)] {
+                    if from == b && !seen[to] {
+                        seen[to] = true;
+                        stack.push(to);
+                    }
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes
+}
+
+proptest! {
+    #[test]
+    fn matches_searching_each_circuit(
+        points in prop::collection::vec([0i64..50, 0i64..50, 0i64..50], 1..12),
+        limit in 0usize..30,
+    ) {
+        let input: String = points.iter().map(|[x, y, z]| format!("{},{},{}\n", x, y, z)).collect();
+        let pairs = sorted_pairs(&points);
+        let n = points.len();
+
+        let sizes = circuit_sizes(n, &pairs[..limit.min(pairs.len())]);
+        let top_three = (sizes.len() >= 3).then(|| sizes[0] * sizes[1] * sizes[2]);
+
+        let last = (1..=pairs.len())
+            .find(|&k| circuit_sizes(n, &pairs[..k]).len() == 1)
+            .map(|k| {
+                let (i, j) = pairs[k - 1];
+                i128::from(points[i][0] * points[j][0])
+            });
+
+        let (part_one, part_two) = day_eight::solve(&input, limit).unwrap();
+        prop_assert_eq!(part_one, top_three);
+        prop_assert_eq!(part_two, last);
+    }
+}
@@ -1,31 +1,19 @@
-use std::collections::HashMap;
+use crate::{Graph, PathError};
 
-pub fn solve(lines: &Vec<String>) -> usize {
-    let graph = super::create_graph(lines);
-    let mut memo = HashMap::new();
-    count_paths("you", &graph, &mut memo)
+pub fn solve(input: &str) -> Result<usize, PathError> {
+    count(&super::create_graph(input)?)
 }
 
-fn count_paths(
-    start_node: &str,
-    graph: &HashMap<String, Vec<String>>,
-    memo: &mut HashMap<String, usize>,
-) -> usize {
-    if start_node == "out" {
-        return 1;
-    }
-
-    if let Some(&cached) = memo.get(start_node) {
-        return cached;
-    }
-
-    let mut total = 0;
-    if let Some(children) = graph.get(start_node) {
-        for child in children {
-            total += count_paths(child, graph, memo);
-        }
-    }
-
-    memo.insert(start_node.to_string(), total);
-    total
+/// Paths from `you` to `out`.
+pub fn count(graph: &Graph) -> Result<usize, PathError> {
+    let (Some(you), Some(out)) = (graph.id("you"), graph.id("out")) else {
+        return Ok(0);
+    };
+    super::count_paths(
+        graph,
+        you,
+        |node| node,
+        |_, child| child,
+        |node| (node == out).then_some(1),
+    )
 }
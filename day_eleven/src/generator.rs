@@ -0,0 +1,87 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayEleven;
+
+/// Layers between `svr` and `out`. The path count grows exponentially with
+/// depth, so it is fixed and `size` only widens the layers.
+const LAYERS: usize = 12;
+
+/// Names the solver gives meaning to; generated devices never use them.
+const RESERVED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+impl Generator for DayEleven {
+    const SIZES: &'static [usize] = &[500, 2_000, 8_000, 32_000];
+
+    /// About `size` devices in layers, each wired to one to three devices in
+    /// the next layer and the last layer wired to `out`. `svr` is the only
+    /// device in the first layer, `you` sits in layer 2, and a chain of devices
+    /// runs from `fft` in layer 4 to `dac` in layer 8, so both parts have paths.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let width = (size / (LAYERS - 1)).max(1);
+
+        let mut names = (0..)
+            .map(device_name)
+            .filter(|n| !RESERVED.contains(&n.as_str()));
+        let mut layers: Vec<Vec<String>> = vec![vec!["svr".to_string()]];
+        layers.extend((1..LAYERS).map(|_| names.by_ref().take(width).collect()));
+        layers[2][0] = "you".to_string();
+        layers[4][0] = "fft".to_string();
+        layers[8][0] = "dac".to_string();
+
+        let mut outputs: Vec<Vec<usize>> = Vec::new();
+        let mut lines = Vec::new();
+        for (depth, layer) in layers.iter().enumerate() {
+            let Some(next) = layers.get(depth + 1) else {
+                lines.extend(layer.iter().map(|name| format!("{}: out", name)));
+                break;
+            };
+
+            // Every device in the next layer gets at least one input...
+            outputs.clear();
+            outputs.resize(layer.len(), Vec::new());
+            for child in 0..next.len() {
+                outputs[rng.index(layer.len())].push(child);
+            }
+            // ...and every device here gets one to three outputs.
+            for wired in outputs.iter_mut() {
+                let target = rng.range(1..=3) as usize;
+                while wired.len() < target.min(next.len()) {
+                    let child = rng.index(next.len());
+                    if !wired.contains(&child) {
+                        wired.push(child);
+                    }
+                }
+            }
+            // Keep fft -> dac connected through the first device of each layer.
+            if (4..8).contains(&depth) && !outputs[0].contains(&0) {
+                outputs[0].push(0);
+            }
+
+            for (name, wired) in layer.iter().zip(&outputs) {
+                let children: Vec<&str> = wired.iter().map(|&c| next[c].as_str()).collect();
+                lines.push(format!("{}: {}", name, children.join(" ")));
+            }
+        }
+
+        rng.shuffle(&mut lines);
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Three lowercase letters for the first 17576 devices, then four, and so on.
+fn device_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    let mut len = 3;
+    let mut count = 26usize.pow(3);
+    while index >= count {
+        index -= count;
+        len += 1;
+        count *= 26;
+    }
+    for _ in 0..len {
+        name.push(b'a' + (index % 26) as u8);
+        index /= 26;
+    }
+    String::from_utf8(name).expect("ASCII name")
+}
@@ -1,18 +1,205 @@
+use anyhow::Result;
+use aoc_common::{OverflowError, ParseError, Solution, non_blank_lines};
 use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
 
 pub mod day_to_out;
+mod generator;
 pub mod svr_to_out;
 
-pub fn create_graph(lines: &Vec<String>) -> HashMap<String, Vec<String>> {
-    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+/// Why the paths through a device graph could not be counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    Parse(ParseError),
+    Overflow(OverflowError),
+    /// The connections from `node` lead back to it, so it has endless paths.
+    Cycle {
+        node: String,
+    },
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Parse(e) => e.fmt(f),
+            PathError::Overflow(e) => e.fmt(f),
+            PathError::Cycle { node } => write!(f, "the connections loop back to '{}'", node),
+        }
+    }
+}
+
+impl Error for PathError {}
+
+impl From<ParseError> for PathError {
+    fn from(e: ParseError) -> Self {
+        PathError::Parse(e)
+    }
+}
+
+impl From<OverflowError> for PathError {
+    fn from(e: OverflowError) -> Self {
+        PathError::Overflow(e)
+    }
+}
+
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+    const DAY: u8 = 11;
+    type Input = Graph;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(create_graph(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        Ok(day_to_out::count(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        Ok(svr_to_out::count(input)?)
+    }
+}
+
+/// The device connections, with each device numbered in order of first
+/// mention so that counting can work on plain indices.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    children: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// The number of device `name`, or `None` if the input never mentions it.
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
 
-    for line in lines {
-        let (node, rest) = line.split_once(':').unwrap();
-        let children = rest
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect::<Vec<_>>();
-        graph.insert(node.to_string(), children);
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
     }
-    graph
+
+    /// The devices that `id` sends its output to.
+    pub fn children(&self, id: usize) -> &[usize] {
+        &self.children[id]
+    }
+
+    /// How many devices the input mentions.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    fn add(&mut self, name: &str) -> usize {
+        if let Some(id) = self.id(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.children.push(Vec::new());
+        id
+    }
+}
+
+/// Parse `node: child child ...` lines into a [`Graph`].
+pub fn create_graph(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::default();
+
+    for (line_no, line) in non_blank_lines(input) {
+        let (node, rest) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::in_line(line_no, line, line, "'node: children'"))?;
+        let node = node.trim();
+        if node.is_empty() || node.contains(char::is_whitespace) {
+            return Err(ParseError::in_line(
+                line_no,
+                line,
+                node,
+                "a single node name",
+            ));
+        }
+        let id = graph.add(node);
+        graph.children[id] = rest.split_whitespace().map(|c| graph.add(c)).collect();
+    }
+    Ok(graph)
+}
+
+/// Paths from `start` to the end of the graph, walking states rather than
+/// devices so a part can carry what a path has seen so far. `node` gives a
+/// state's device, `step` the state reached by following a wire to a device,
+/// and `end` the value of a state where paths stop (`None` for the rest).
+///
+/// Works with an explicit stack of partly counted states instead of
+/// recursion, so a long chain of devices cannot overflow the call stack.
+/// `memo` holds `None` for states still being counted, so reaching one again
+/// means the graph has a cycle.
+pub(crate) fn count_paths<S: Copy + Eq + Hash>(
+    graph: &Graph,
+    start: S,
+    node: impl Fn(S) -> usize,
+    step: impl Fn(S, usize) -> S,
+    end: impl Fn(S) -> Option<usize>,
+) -> Result<usize, PathError> {
+    if let Some(value) = end(start) {
+        return Ok(value);
+    }
+    let mut memo: HashMap<S, Option<usize>> = HashMap::from([(start, None)]);
+    // Each frame is a state, the index of its next wire and its total so far.
+    let mut stack = vec![(start, 0, 0)];
+
+    while let Some((state, next, total)) = stack.last_mut() {
+        let here = node(*state);
+        let Some(&child) = graph.children(here).get(*next) else {
+            let (state, _, total) = stack.pop().expect("a state being counted");
+            memo.insert(state, Some(total));
+            let Some((parent, _, parent_total)) = stack.last_mut() else {
+                return Ok(total);
+            };
+            *parent_total = parent_total
+                .checked_add(total)
+                .ok_or_else(|| overflow_error(graph.name(node(*parent))))?;
+            continue;
+        };
+        *next += 1;
+
+        let child = step(*state, child);
+        let paths = match end(child) {
+            Some(value) => value,
+            None => match memo.get(&child) {
+                Some(&Some(paths)) => paths,
+                Some(None) => return Err(cycle_error(graph.name(node(child)))),
+                None => {
+                    memo.insert(child, None);
+                    stack.push((child, 0, 0));
+                    continue;
+                }
+            },
+        };
+        *total = total
+            .checked_add(paths)
+            .ok_or_else(|| overflow_error(graph.name(here)))?;
+    }
+
+    unreachable!("the start state is counted last")
+}
+
+/// Error for reaching `node` while its own paths are still being counted.
+fn cycle_error(node: &str) -> PathError {
+    PathError::Cycle {
+        node: node.to_string(),
+    }
+}
+
+/// Error for the paths from `node` adding up past `usize`.
+fn overflow_error(node: &str) -> PathError {
+    OverflowError::new::<usize>(format!("the number of paths from '{}'", node)).into()
 }
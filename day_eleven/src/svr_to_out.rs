@@ -1,40 +1,31 @@
-use std::collections::HashMap;
+use crate::{Graph, PathError};
 
-pub fn solve(lines: &Vec<String>) -> usize {
-    let graph = super::create_graph(lines);
-    let mut memo = HashMap::new();
-    count_paths_with_requirements("svr", &graph, false, false, &mut memo)
+pub fn solve(input: &str) -> Result<usize, PathError> {
+    count(&super::create_graph(input)?)
 }
 
-fn count_paths_with_requirements(
-    start_node: &str,
-    graph: &HashMap<String, Vec<String>>,
-    seen_dac: bool,
-    seen_fft: bool,
-    memo: &mut HashMap<(String, bool, bool), usize>,
-) -> usize {
-    let seen_dac = seen_dac || start_node == "dac";
-    let seen_fft = seen_fft || start_node == "fft";
+/// Paths from `svr` to `out` that pass through both `dac` and `fft`.
+///
+/// Each state is a device and whether the path has seen `dac` and `fft` on
+/// the way; only paths reaching `out` having seen both count.
+pub fn count(graph: &Graph) -> Result<usize, PathError> {
+    let (Some(svr), Some(out)) = (graph.id("svr"), graph.id("out")) else {
+        return Ok(0);
+    };
+    let (dac, fft) = (graph.id("dac"), graph.id("fft"));
+    let visit = |node, seen_dac: bool, seen_fft: bool| {
+        (
+            node,
+            seen_dac || dac == Some(node),
+            seen_fft || fft == Some(node),
+        )
+    };
 
-    let key = (start_node.to_string(), seen_dac, seen_fft);
-
-    if let Some(&cached) = memo.get(&key) {
-        return cached;
-    }
-
-    if start_node == "out" {
-        let result = if seen_dac && seen_fft { 1 } else { 0 };
-        memo.insert(key, result);
-        return result;
-    }
-
-    let mut total = 0;
-    if let Some(children) = graph.get(start_node) {
-        for next in children {
-            total += count_paths_with_requirements(next, graph, seen_dac, seen_fft, memo);
-        }
-    }
-
-    memo.insert(key, total);
-    total
+    super::count_paths(
+        graph,
+        visit(svr, false, false),
+        |(node, _, _)| node,
+        |(_, seen_dac, seen_fft), child| visit(child, seen_dac, seen_fft),
+        |(node, seen_dac, seen_fft)| (node == out).then_some(usize::from(seen_dac && seen_fft)),
+    )
 }
@@ -1,12 +1,13 @@
+use aoc_common::{OverflowError, ParseError};
+use day_eleven::PathError;
 use day_eleven::day_to_out::solve as solve_part_one;
 use day_eleven::svr_to_out::solve as solve_part_two;
 
 #[test]
 fn example_part_one() {
     let s = include_str!("../example_p1.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let result = solve_part_one(&lines);
+    let result = solve_part_one(s).unwrap();
 
     assert_eq!(result, 5, "Expected 5 total paths from day to out");
 }
@@ -14,12 +15,97 @@ fn example_part_one() {
 #[test]
 fn example_part_two() {
     let s = include_str!("../example_p2.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let result = solve_part_two(&lines);
+    let result = solve_part_two(s).unwrap();
 
     assert_eq!(
         result, 2,
         "Expected 2 paths from svr to out that visit both dac and fft"
     );
 }
+
+#[test]
+fn missing_colon_is_an_error() {
+    let err = solve_part_one("you: out\naaa bbb\n").unwrap_err();
+    assert_eq!(
+        err,
+        PathError::Parse(ParseError::new(2, 1, "aaa bbb", "'node: children'"))
+    );
+}
+
+#[test]
+fn cycles_are_errors() {
+    let err = solve_part_one("you: aaa\naaa: bbb out\nbbb: aaa\n").unwrap_err();
+    assert_eq!(
+        err,
+        PathError::Cycle {
+            node: "aaa".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "the connections loop back to 'aaa'");
+
+    let err = solve_part_two("svr: dac\ndac: fft\nfft: svr out\n").unwrap_err();
+    assert_eq!(
+        err,
+        PathError::Cycle {
+            node: "fft".to_string()
+        }
+    );
+
+    // A cycle that cannot be reached from the start does not matter.
+    assert_eq!(solve_part_one("you: out\naaa: bbb\nbbb: aaa\n"), Ok(1));
+}
+
+#[test]
+fn too_many_paths_is_an_error() {
+    // Each layer doubles the paths: n0 -> {a0, b0} -> n1 -> ...
+    let mut input = String::from("you: n0\n");
+    for i in 0..64 {
+        input += &format!("n{i}: a{i} b{i}\na{i}: n{}\nb{i}: n{}\n", i + 1, i + 1);
+    }
+    input += "n64: out\n";
+    let err = solve_part_one(&input).unwrap_err();
+    assert_eq!(
+        err,
+        PathError::Overflow(OverflowError::new::<usize>("the number of paths from 'n0'"))
+    );
+}
+
+#[test]
+fn input_is_parsed_once() {
+    use aoc_common::Solution;
+    use day_eleven::DayEleven;
+
+    // A malformed line fails `parse`, before either part runs.
+    assert!(DayEleven::parse("you: out\naaa bbb\n").is_err());
+
+    let graph = DayEleven::parse(include_str!("../example_p1.txt")).unwrap();
+    let you = graph.id("you").unwrap();
+    let children: Vec<&str> = graph.children(you).iter().map(|&c| graph.name(c)).collect();
+    assert_eq!(children, ["bbb", "ccc"]);
+    assert_eq!(DayEleven::part_one(&graph).unwrap(), 5);
+}
+
+#[test]
+fn deep_chains_do_not_overflow_the_stack() {
+    // A chain of devices far deeper than a recursive count could follow.
+    let depth = 200_000;
+    let mut input = String::from("you: n0\nsvr: n0\n");
+    for i in 0..depth {
+        let name = match i {
+            1_000 => "dac".to_string(),
+            150_000 => "fft".to_string(),
+            _ => format!("n{i}"),
+        };
+        let next = match i + 1 {
+            1_000 => "dac".to_string(),
+            150_000 => "fft".to_string(),
+            n if n == depth => "out".to_string(),
+            n => format!("n{n}"),
+        };
+        input += &format!("{name}: {next}\n");
+    }
+
+    assert_eq!(solve_part_one(&input), Ok(1));
+    assert_eq!(solve_part_two(&input), Ok(1));
+}
@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+
+/// Devices in topological order; every wire runs from an earlier device to a
+/// later one, so the graph has no cycles. `out` always comes last.
+const DEVICES: [&str; 8] = ["svr", "aaa", "you", "fft", "bbb", "dac", "ccc", "out"];
+
+/// Walk every path from `node` to `out` one by one, counting those that pass
+/// through all of `required`.
+fn walk(wires: &[Vec<usize>], node: usize, required: &[&str], seen: usize) -> usize {
+    let seen = seen + required.iter().filter(|&&r| DEVICES[node] == r).count();
+    if DEVICES[node] == "out" {
+        return usize::from(seen == required.len());
+    }
+    wires[node]
+        .iter()
+        .map(|&next| walk(wires, next, required, seen))
+        .sum()
+}
+
+fn wires() -> impl Strategy<Value = (Vec<usize>, Vec<Vec<bool>>)> {
+    (
+        Just((0..DEVICES.len() - 1).collect::<Vec<_>>()).prop_shuffle(),
+        prop::collection::vec(
+            prop::collection::vec(any::<bool>(), DEVICES.len()),
+            DEVICES.len(),
+        ),
+    )
+}
+
+proptest! {
+    #[test]
+    fn matches_walking_every_path((order, links) in wires()) {
+        // Position of each device in a shuffled order, with `out` kept last.
+        let mut rank = vec![DEVICES.len() - 1; DEVICES.len()];
+        for (pos, &device) in order.iter().enumerate() {
+            rank[device] = pos;
+        }
+        let wires: Vec<Vec<usize>> = (0..DEVICES.len())
+            .map(|from| {
+                (0..DEVICES.len())
+                    .filter(|&to| rank[from] < rank[to] && links[from][to])
+                    .collect()
+            })
+            .collect();
+
+        let input: String = wires
+            .iter()
+            .enumerate()
+            .filter(|&(from, _)| DEVICES[from] != "out")
+            .map(|(from, to)| {
+                let to: Vec<&str> = to.iter().map(|&t| DEVICES[t]).collect();
+                format!("{}: {}\n", DEVICES[from], to.join(" "))
+            })
+            .collect();
+
+        let you = DEVICES.iter().position(|&d| d == "you").unwrap();
+        prop_assert_eq!(
+            day_eleven::day_to_out::solve(&input).unwrap(),
+            walk(&wires, you, &[], 0)
+        );
+        prop_assert_eq!(
+            day_eleven::svr_to_out::solve(&input).unwrap(),
+            walk(&wires, 0, &["dac", "fft"], 0)
+        );
+    }
+}
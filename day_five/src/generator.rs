@@ -0,0 +1,28 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayFive;
+
+/// Largest id in the puzzle input is around 5.6e14.
+const MAX_ID: u64 = 560_000_000_000_000;
+
+impl Generator for DayFive {
+    const SIZES: &'static [usize] = &[1_000, 10_000, 100_000, 1_000_000];
+
+    /// `size` fresh ranges, often overlapping, followed by `size` ids to check.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            let start = rng.range(1..=MAX_ID);
+            let end = start
+                .saturating_add(rng.range(0..=MAX_ID / 100))
+                .min(MAX_ID);
+            input.push_str(&format!("{}-{}\n", start, end));
+        }
+        input.push('\n');
+        for _ in 0..size {
+            input.push_str(&format!("{}\n", rng.range(1..=MAX_ID)));
+        }
+        input
+    }
+}
@@ -1,41 +1,94 @@
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Result, anyhow};
+use aoc_common::{ParseError, Solution, parse_integer, split_sections};
+
+mod generator;
 
 type Parsed = (Vec<(u64, u64)>, Vec<u64>);
 
-/// Parse the input lines into a list of ranges and a list of ids.
-pub fn parse_input(lines: Vec<String>) -> Result<Parsed> {
-    let blank_index = lines
-        .iter()
-        .position(|l| l.trim().is_empty())
-        .ok_or_else(|| anyhow!("input missing blank separator line"))?;
+pub struct DayFive;
+
+impl Solution for DayFive {
+    const DAY: u8 = 5;
+    /// Merged ranges and the ids to check against them.
+    type Input = Parsed;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (ranges, ids) = parse_input(input)?;
+        Ok((merge_ranges(ranges), ids))
+    }
+
+    fn part_one((merged, ids): &Self::Input) -> Result<usize> {
+        Ok(get_fresh_count(merged, ids))
+    }
+
+    fn part_two((merged, _): &Self::Input) -> Result<u64> {
+        get_total_fresh(merged).ok_or_else(|| anyhow!("more fresh ids than fit in a u64"))
+    }
+}
 
-    let range_lines = &lines[..blank_index];
-    let id_lines = &lines[blank_index + 1..];
+/// Parse the input into a list of ranges and a list of ids, given as two
+/// blocks separated by a blank line. A blank line with no ids after it gives
+/// an empty id list.
+pub fn parse_input(input: &str) -> Result<Parsed, ParseError> {
+    let sections = split_sections(input);
+    let (range_section, id_section) = match sections.as_slice() {
+        // Only blank lines can follow the last line of the only section.
+        [ranges]
+            if input
+                .lines()
+                .nth(ranges.first_line - 1 + ranges.lines().count())
+                .is_some() =>
+        {
+            (ranges, None)
+        }
+        [ranges, ids] => (ranges, Some(ids)),
+        [_, _, extra, ..] => {
+            return Err(ParseError::new(
+                extra.first_line,
+                1,
+                extra.lines().next().unwrap_or(""),
+                "end of input after the ids",
+            ));
+        }
+        _ => {
+            return Err(ParseError::new(
+                input.lines().count() + 1,
+                1,
+                "",
+                "a blank line between the ranges and the ids",
+            ));
+        }
+    };
 
-    let mut ranges = Vec::with_capacity(range_lines.len());
-    for line in range_lines {
+    let parse_u64 = |line_no: usize, line: &str, s: &str, what: &str| {
+        parse_integer::<u64>(line_no, line, s.trim(), what)
+    };
+
+    let mut ranges = Vec::new();
+    for (line_no, line) in range_section.numbered_lines() {
         let (start_s, end_s) = line
             .split_once('-')
-            .with_context(|| format!("invalid range line: '{}'", line))?;
-        let start = start_s
-            .trim()
-            .parse::<u64>()
-            .with_context(|| format!("invalid start in line: '{}'", line))?;
-        let end = end_s
-            .trim()
-            .parse::<u64>()
-            .with_context(|| format!("invalid end in line: '{}'", line))?;
+            .ok_or_else(|| ParseError::in_line(line_no, line, line, "a range 'start-end'"))?;
+        let start = parse_u64(line_no, line, start_s, "a range start")?;
+        let end = parse_u64(line_no, line, end_s, "a range end")?;
+        if end < start {
+            return Err(ParseError::in_line(
+                line_no,
+                line,
+                end_s.trim(),
+                "a range end not before its start",
+            ));
+        }
         ranges.push((start, end));
     }
 
-    let mut ids = Vec::with_capacity(id_lines.len());
-    for s in id_lines {
-        let id = s
-            .trim()
-            .parse::<u64>()
-            .with_context(|| format!("invalid id line: '{}'", s))?;
-        ids.push(id);
-    }
+    let ids = id_section
+        .iter()
+        .flat_map(|section| section.numbered_lines())
+        .map(|(line_no, line)| parse_u64(line_no, line, line, "an ingredient id"))
+        .collect::<Result<Vec<_>, _>>()?;
 
     Ok((ranges, ids))
 }
@@ -81,14 +134,19 @@ pub fn get_fresh_count(merged: &[(u64, u64)], ids: &[u64]) -> usize {
     ids.iter().filter(|&&id| is_fresh(merged, id)).count()
 }
 
-pub fn get_total_fresh(merged: &[(u64, u64)]) -> u64 {
-    merged.iter().map(|&(s, e)| e - s + 1).sum()
+/// Number of ids covered by the merged ranges, or `None` if that is more
+/// than a `u64` can hold (only possible when they cover every id).
+pub fn get_total_fresh(merged: &[(u64, u64)]) -> Option<u64> {
+    merged.iter().try_fold(0u64, |total, &(s, e)| {
+        (e - s).checked_add(1).and_then(|n| total.checked_add(n))
+    })
 }
 
-pub fn solve(lines: Vec<String>) -> Result<(usize, u64)> {
-    let (ranges, ids) = parse_input(lines)?;
+pub fn solve(input: &str) -> Result<(usize, u64)> {
+    let (ranges, ids) = parse_input(input)?;
     let merged = merge_ranges(ranges);
     let fresh_count = get_fresh_count(&merged, &ids);
-    let total = get_total_fresh(&merged);
+    let total =
+        get_total_fresh(&merged).ok_or_else(|| anyhow!("more fresh ids than fit in a u64"))?;
     Ok((fresh_count, total))
 }
@@ -1,13 +1,6 @@
 #[test]
 fn merge_and_solve_smoke() {
-    let input = vec![
-        "1-3".to_string(),
-        "5-5".to_string(),
-        "".to_string(),
-        "2".to_string(),
-        "5".to_string(),
-        "6".to_string(),
-    ];
+    let input = "1-3\n5-5\n\n2\n5\n6\n";
 
     let (fresh_count, total) = day_five::solve(input).expect("solve failed");
     assert_eq!(total, 4);
@@ -20,3 +13,35 @@ fn merged_properties() {
     let merged = day_five::merge_ranges(ranges);
     assert_eq!(merged, vec![(1, 6), (8, 9)]);
 }
+
+#[test]
+fn parse_errors_report_position() {
+    let input = "1-3\n\n2\nx\n";
+    let err = day_five::parse_input(input).unwrap_err();
+    assert_eq!(
+        err,
+        aoc_common::ParseError::new(4, 1, "x", "an ingredient id")
+    );
+
+    let err = day_five::parse_input("1-3\n").unwrap_err();
+    assert_eq!(err.line, 2);
+
+    // The blank line is there, so there are just no ids to check.
+    assert_eq!(day_five::parse_input("1-3\n\n"), Ok((vec![(1, 3)], vec![])));
+    assert_eq!(day_five::solve("0-0\n\n").unwrap(), (0, 1));
+
+    let err = day_five::parse_input("1-3\n\n2\n\n4\n").unwrap_err();
+    assert_eq!(err.line, 5);
+}
+
+#[test]
+fn reversed_ranges_and_overflowing_totals_are_errors() {
+    let err = day_five::parse_input("5-3\n\n4\n").unwrap_err();
+    assert_eq!(
+        err,
+        aoc_common::ParseError::new(1, 3, "3", "a range end not before its start")
+    );
+
+    let input = format!("0-{}\n\n4\n", u64::MAX);
+    assert!(day_five::solve(&input).is_err());
+}
@@ -35,3 +35,24 @@ proptest! {
         prop_assert_eq!(original_set, merged_set);
     }
 }
+
+proptest! {
+    #[test]
+    fn solve_matches_expanded_ranges(
+        ranges in prop::collection::vec((0u64..100u64, 0u64..20u64), 1..10),
+        ids in prop::collection::vec(0u64..130u64, 0..20),
+    ) {
+        let ranges: Vec<(u64, u64)> = ranges.iter().map(|&(s, w)| (s, s + w)).collect();
+        let mut input: String = ranges.iter().map(|(s, e)| format!("{}-{}\n", s, e)).collect();
+        input.push('\n');
+        input.extend(ids.iter().map(|id| format!("{}\n", id)));
+
+        let fresh = expand_ranges(&ranges);
+        let expected_count = ids.iter().filter(|id| fresh.contains(id)).count();
+
+        prop_assert_eq!(
+            day_five::solve(&input).unwrap(),
+            (expected_count, fresh.len() as u64)
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayFour;
+
+impl Generator for DayFour {
+    const SIZES: &'static [usize] = &[50, 100, 200, 400];
+
+    /// A `size` by `size` grid with a roll of paper on about 60% of the cells.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(if rng.chance(0.6) { '@' } else { '.' });
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -1,37 +1,42 @@
-pub fn parse_grid(lines: &[String]) -> Vec<Vec<char>> {
-    lines.iter().map(|row| row.chars().collect()).collect()
+use anyhow::Result;
+use aoc_common::{Grid, ParseError, Solution};
+
+mod generator;
+
+pub struct DayFour;
+
+impl Solution for DayFour {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_grid(input)?)
+    }
+
+    fn part_one(map: &Self::Input) -> Result<usize> {
+        Ok(count_accessible(map))
+    }
+
+    fn part_two(map: &Self::Input) -> Result<usize> {
+        Ok(removed_total(map))
+    }
+}
+
+pub fn parse_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input.lines())
 }
 
 /// Return true if the cell at (r,c) is an '@' and has fewer than 4 adjacent '@'s.
-pub fn is_accessible(map: &[Vec<char>], r: usize, c: usize) -> bool {
-    if map[r][c] != '@' {
+pub fn is_accessible(map: &Grid<char>, r: usize, c: usize) -> bool {
+    if map[(r, c)] != '@' {
         return false;
     }
 
-    let h = map.len();
-    let w = map[0].len();
-
-    let dirs = [
-        (-1, -1),
-        (-1, 0),
-        (-1, 1),
-        (0, -1),
-        (0, 1),
-        (1, -1),
-        (1, 0),
-        (1, 1),
-    ];
-
     let mut adj = 0usize;
-    for (dr, dc) in dirs {
-        let nr = r as isize + dr;
-        let nc = c as isize + dc;
-        if nr >= 0
-            && nr < h as isize
-            && nc >= 0
-            && nc < w as isize
-            && map[nr as usize][nc as usize] == '@'
-        {
+    for pos in map.neighbours8((r, c)) {
+        if map[pos] == '@' {
             adj += 1;
             if adj >= 4 {
                 return false;
@@ -42,50 +47,27 @@ pub fn is_accessible(map: &[Vec<char>], r: usize, c: usize) -> bool {
     adj < 4
 }
 
-pub fn count_accessible(lines: &[String]) -> usize {
-    if lines.is_empty() {
-        return 0;
-    }
-    let map = parse_grid(lines);
-    let h = map.len();
-    let w = map[0].len();
-
-    let mut accessible = 0usize;
-    for r in 0..h {
-        for c in 0..w {
-            if is_accessible(&map, r, c) {
-                accessible += 1;
-            }
-        }
-    }
-    accessible
+pub fn count_accessible(map: &Grid<char>) -> usize {
+    map.positions()
+        .filter(|&(r, c)| is_accessible(map, r, c))
+        .count()
 }
 
-pub fn removed_total(lines: &[String]) -> usize {
-    if lines.is_empty() {
-        return 0;
-    }
-    let h = lines.len();
-    let w = lines[0].len();
-
-    let mut map: Vec<Vec<char>> = parse_grid(lines);
+pub fn removed_total(map: &Grid<char>) -> usize {
+    let mut map = map.clone();
     let mut total_removed = 0usize;
 
     loop {
-        let mut to_remove = Vec::new();
-        for r in 0..h {
-            for c in 0..w {
-                if is_accessible(&map, r, c) {
-                    to_remove.push((r, c));
-                }
-            }
-        }
+        let to_remove: Vec<(usize, usize)> = map
+            .positions()
+            .filter(|&(r, c)| is_accessible(&map, r, c))
+            .collect();
         let removed_this_round = to_remove.len();
         if removed_this_round == 0 {
             break;
         }
-        for (r, c) in to_remove {
-            map[r][c] = '.';
+        for pos in to_remove {
+            map[pos] = '.';
         }
         total_removed += removed_this_round;
     }
@@ -93,10 +75,11 @@ pub fn removed_total(lines: &[String]) -> usize {
     total_removed
 }
 
-pub fn solve(lines: Vec<String>) -> (usize, usize) {
-    let accessible = count_accessible(&lines);
-    let rtotal = removed_total(&lines);
-    (accessible, rtotal)
+pub fn solve(input: &str) -> Result<(usize, usize), ParseError> {
+    let map = parse_grid(input)?;
+    let accessible = count_accessible(&map);
+    let rtotal = removed_total(&map);
+    Ok((accessible, rtotal))
 }
 
 #[cfg(test)]
@@ -105,15 +88,15 @@ mod tests {
 
     #[test]
     fn test_count_accessible_simple() {
-        let lines = vec!["@@@".to_string(), "...".to_string(), "..@".to_string()];
+        let map = Grid::parse(["@@@", "...", "..@"]).unwrap();
         // accessible positions: all top row (3) and bottom-right (1) => 4
-        assert_eq!(count_accessible(&lines), 4);
+        assert_eq!(count_accessible(&map), 4);
     }
 
     #[test]
     fn test_removed_total_simple() {
-        let lines = vec!["@@@".to_string(), "...".to_string()];
+        let map = Grid::parse(["@@@", "..."]).unwrap();
         // all three in top row are accessible and removed in one round
-        assert_eq!(removed_total(&lines), 3);
+        assert_eq!(removed_total(&map), 3);
     }
 }
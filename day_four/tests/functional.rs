@@ -1,16 +1,20 @@
 use day_four as lib;
 #[test]
 fn simple_counts() {
-    let lines = vec!["@@@".to_string(), "...".to_string(), "..@".to_string()];
-    let (p1, p2) = lib::solve(lines.clone());
+    let (p1, p2) = lib::solve("@@@\n...\n..@\n").unwrap();
     assert_eq!(p1, 4);
     assert_eq!(p2, 4);
 }
 
 #[test]
 fn removal_rounds() {
-    let lines = vec!["@@@".to_string(), "...".to_string()];
-    let (p1, p2) = lib::solve(lines.clone());
+    let (p1, p2) = lib::solve("@@@\n...\n").unwrap();
     assert_eq!(p1, 3);
     assert_eq!(p2, 3);
 }
+
+#[test]
+fn ragged_grid_is_an_error() {
+    let err = lib::solve("@@@\n@.\n").unwrap_err();
+    assert_eq!(err.line, 2);
+}
@@ -0,0 +1,57 @@
+use proptest::prelude::*;
+
+fn accessible(rolls: &[Vec<bool>], r: usize, c: usize) -> bool {
+    let mut adjacent = 0;
+    for dr in -1i64..=1 {
+        for dc in -1i64..=1 {
+            let (nr, nc) = (r as i64 + dr, c as i64 + dc);
+            if (dr, dc) != (0, 0)
+                && nr >= 0
+                && nc >= 0
+                && rolls
+                    .get(nr as usize)
+                    .and_then(|row| row.get(nc as usize))
+                    .copied()
+                    .unwrap_or(false)
+            {
+                adjacent += 1;
+            }
+        }
+    }
+    rolls[r][c] && adjacent < 4
+}
+
+/// Count accessible rolls, then remove them one at a time in scan order until
+/// none are left. Removing a roll never blocks another, so this must remove
+/// the same rolls as removing them a round at a time.
+fn one_at_a_time(mut rolls: Vec<Vec<bool>>) -> (usize, usize) {
+    let cells: Vec<(usize, usize)> = (0..rolls.len())
+        .flat_map(|r| (0..rolls[r].len()).map(move |c| (r, c)))
+        .collect();
+    let first = cells
+        .iter()
+        .filter(|&&(r, c)| accessible(&rolls, r, c))
+        .count();
+
+    let mut removed = 0;
+    while let Some(&(r, c)) = cells.iter().find(|&&(r, c)| accessible(&rolls, r, c)) {
+        rolls[r][c] = false;
+        removed += 1;
+    }
+    (first, removed)
+}
+
+proptest! {
+    #[test]
+    fn matches_one_at_a_time_removal(
+        rolls in (1usize..12).prop_flat_map(|w| {
+            prop::collection::vec(prop::collection::vec(any::<bool>(), w), 1..12)
+        })
+    ) {
+        let input: String = rolls
+            .iter()
+            .map(|row| row.iter().map(|&r| if r { '@' } else { '.' }).collect::<String>() + "\n")
+            .collect();
+        prop_assert_eq!(day_four::solve(&input).unwrap(), one_at_a_time(rolls));
+    }
+}
@@ -1,3 +1,4 @@
+use aoc_common::Grid;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 pub type CoordMap = HashMap<i32, usize>;
@@ -24,6 +25,14 @@ pub fn compress_coords(points: &[(i32, i32)]) -> (Vec<i32>, Vec<i32>, CoordMap,
         ys_set.insert(y);
         ys_set.insert(y + 1);
     }
+    // A ring of cells around the loop, so the outside is one connected
+    // region that contains the grid's corner.
+    for set in [&mut xs_set, &mut ys_set] {
+        if let (Some(&lo), Some(&hi)) = (set.iter().min(), set.iter().max()) {
+            set.insert(lo - 1);
+            set.insert(hi + 1);
+        }
+    }
     let mut xs: Vec<i32> = xs_set.into_iter().collect();
     let mut ys: Vec<i32> = ys_set.into_iter().collect();
     xs.sort_unstable();
@@ -45,10 +54,10 @@ pub fn build_compressed_grid(
     ys: &[i32],
     x_to_idx: &CoordMap,
     y_to_idx: &CoordMap,
-) -> (Vec<Vec<bool>>, usize, usize) {
+) -> Grid<bool> {
     let width = xs.len().saturating_sub(1);
     let height = ys.len().saturating_sub(1);
-    let mut green = vec![vec![false; width]; height];
+    let mut green = Grid::new(width, height, false);
     let n = points.len();
     for i in 0..n {
         let (x1, y1) = points[i];
@@ -60,8 +69,8 @@ pub fn build_compressed_grid(
             let iy1 = *y_to_idx.get(&a).unwrap();
             let iy2 = *y_to_idx.get(&(b + 1)).unwrap();
             if ix < width {
-                for row in green.iter_mut().take(iy2).skip(iy1) {
-                    row[ix] = true;
+                for iy in iy1..iy2.min(height) {
+                    green[(iy, ix)] = true;
                 }
             }
         } else {
@@ -71,96 +80,86 @@ pub fn build_compressed_grid(
             let ix1 = *x_to_idx.get(&a).unwrap();
             let ix2 = *x_to_idx.get(&(b + 1)).unwrap();
             if iy < height {
-                let row = &mut green[iy];
+                let row = green.row_mut(iy);
                 for cell in row.iter_mut().take(ix2).skip(ix1) {
                     *cell = true;
                 }
             }
         }
     }
-    (green, width, height)
+    green
 }
 
-pub fn flood_fill_compressed(green: &mut [Vec<bool>]) {
-    let height = green.len();
-    if height == 0 {
-        return;
-    }
-    let width = green[0].len();
-    if width == 0 {
+/// Mark every cell inside the loop drawn on `green`: whatever cannot be
+/// reached from the corner cell, which lies outside the padded grid, without
+/// crossing the loop.
+pub fn flood_fill_compressed(green: &mut Grid<bool>) {
+    if green.is_empty() {
         return;
     }
-    let start_x = width / 2;
-    let start_y = height / 2;
+    let mut outside = Grid::new(green.width(), green.height(), false);
     let mut q: VecDeque<(usize, usize)> = VecDeque::new();
-    if !green[start_y][start_x] {
-        green[start_y][start_x] = true;
-        q.push_back((start_x, start_y));
+    if !green[(0, 0)] {
+        outside[(0, 0)] = true;
+        q.push_back((0, 0));
     }
-    while let Some((cx, cy)) = q.pop_front() {
-        let neigh = [(-1isize, 0), (1, 0), (0, -1), (0, 1)];
-        for (dx, dy) in neigh {
-            let nx = cx as isize + dx;
-            let ny = cy as isize + dy;
-            if nx >= 0 && nx < width as isize && ny >= 0 && ny < height as isize {
-                let (nxu, nyu) = (nx as usize, ny as usize);
-                if !green[nyu][nxu] {
-                    green[nyu][nxu] = true;
-                    q.push_back((nxu, nyu));
-                }
-            }
+    while let Some(pos) = q.pop_front() {
+        let unvisited: Vec<(usize, usize)> = green
+            .neighbours4(pos)
+            .filter(|&n| !green[n] && !outside[n])
+            .collect();
+        for n in unvisited {
+            outside[n] = true;
+            q.push_back(n);
+        }
+    }
+    for (pos, &out) in outside.iter() {
+        if !out {
+            green[pos] = true;
         }
     }
 }
 
 pub fn mark_red_tiles(
-    green: &mut [Vec<bool>],
+    green: &mut Grid<bool>,
     points: &[(i32, i32)],
     x_to_idx: &CoordMap,
     y_to_idx: &CoordMap,
-    width: usize,
-    height: usize,
 ) {
     for &(x, y) in points {
-        match (x_to_idx.get(&x), y_to_idx.get(&y)) {
-            (Some(&ix), Some(&iy)) if ix < width && iy < height => green[iy][ix] = true,
-            _ => {}
+        if let (Some(&ix), Some(&iy)) = (x_to_idx.get(&x), y_to_idx.get(&y))
+            && let Some(cell) = green.get_mut((iy, ix))
+        {
+            *cell = true;
         }
     }
 }
 
-pub fn build_weighted_prefix_sum(
-    green: &[Vec<bool>],
-    xs: &[i32],
-    ys: &[i32],
-    width: usize,
-    height: usize,
-) -> Vec<u64> {
-    let mut ps = vec![0u64; (width + 1) * (height + 1)];
-    for iy in 0..height {
-        for ix in 0..width {
-            let cell_area = ((xs[ix + 1] - xs[ix]) as i64 * (ys[iy + 1] - ys[iy]) as i64) as u64;
-            let v = if green[iy][ix] { cell_area } else { 0 };
-            ps[(iy + 1) * (width + 1) + (ix + 1)] = v;
-        }
+/// Summed-area table over the compressed grid, one row and column larger
+/// than `green`, where each allowed cell contributes its real tile count.
+pub fn build_weighted_prefix_sum(green: &Grid<bool>, xs: &[i32], ys: &[i32]) -> Grid<u64> {
+    let (width, height) = (green.width(), green.height());
+    let mut ps = Grid::new(width + 1, height + 1, 0u64);
+    for ((iy, ix), &allowed) in green.iter() {
+        let cell_area =
+            u64::from(xs[ix].abs_diff(xs[ix + 1])) * u64::from(ys[iy].abs_diff(ys[iy + 1]));
+        ps[(iy + 1, ix + 1)] = if allowed { cell_area } else { 0 };
     }
     for y in 1..=height {
         for x in 1..=width {
-            let idx = y * (width + 1) + x;
-            let above = ps[(y - 1) * (width + 1) + x];
-            let left = ps[y * (width + 1) + (x - 1)];
-            let diag = ps[(y - 1) * (width + 1) + (x - 1)];
-            ps[idx] += above + left - diag;
+            let above = ps[(y - 1, x)];
+            let left = ps[(y, x - 1)];
+            let diag = ps[(y - 1, x - 1)];
+            ps[(y, x)] += above + left - diag;
         }
     }
     ps
 }
 
 pub struct QueryHelper<'a> {
-    pub ps: &'a [u64],
+    pub ps: &'a Grid<u64>,
     pub x_to_idx: &'a CoordMap,
     pub y_to_idx: &'a CoordMap,
-    pub width: usize,
 }
 
 pub fn rect_allowed_area_ctx(x1: i32, y1: i32, x2: i32, y2: i32, ctx: &QueryHelper) -> u64 {
@@ -172,9 +171,9 @@ pub fn rect_allowed_area_ctx(x1: i32, y1: i32, x2: i32, y2: i32, ctx: &QueryHelp
     let iy1 = *ctx.y_to_idx.get(&min_yy).unwrap();
     let ix2 = *ctx.x_to_idx.get(&(max_xx + 1)).unwrap();
     let iy2 = *ctx.y_to_idx.get(&(max_yy + 1)).unwrap();
-    let a = ctx.ps[iy2 * (ctx.width + 1) + ix2];
-    let b = ctx.ps[iy1 * (ctx.width + 1) + ix2];
-    let c = ctx.ps[iy2 * (ctx.width + 1) + ix1];
-    let d = ctx.ps[iy1 * (ctx.width + 1) + ix1];
+    let a = ctx.ps[(iy2, ix2)];
+    let b = ctx.ps[(iy1, ix2)];
+    let c = ctx.ps[(iy2, ix1)];
+    let d = ctx.ps[(iy1, ix1)];
     a + d - b - c
 }
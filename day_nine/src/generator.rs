@@ -0,0 +1,38 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayNine;
+
+/// Keeps the loop clear of the origin, like the puzzle input.
+const MARGIN: u64 = 1_000;
+
+impl Generator for DayNine {
+    const SIZES: &'static [usize] = &[250, 500, 1_000, 2_000];
+
+    /// A closed loop of about `size` red tiles, each joined to the next by a
+    /// straight line. The loop is a skyline: a flat bottom edge with columns of
+    /// random height above it, so it never crosses itself.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let columns = (size.saturating_sub(2) / 2).max(1);
+
+        let mut x = MARGIN;
+        let mut height = 0;
+        let mut points = vec![(x, MARGIN)];
+        for _ in 0..columns {
+            let mut next = rng.range(MARGIN + 1..=MARGIN + 99_000);
+            while next == height {
+                next = rng.range(MARGIN + 1..=MARGIN + 99_000);
+            }
+            height = next;
+            points.push((x, height));
+            x += rng.range(1..=200);
+            points.push((x, height));
+        }
+        points.push((x, MARGIN));
+
+        points
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect()
+    }
+}
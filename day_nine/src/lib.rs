@@ -1,26 +1,56 @@
+use anyhow::Result;
+use aoc_common::{ParseError, Solution, non_blank_lines, parse_integer};
+
 pub mod compressed;
+mod generator;
 
-pub fn solve(lines: &[String]) -> (u64, u64) {
-    let mut points: Vec<(i32, i32)> = Vec::new();
+/// Largest coordinate magnitude accepted. Keeps differences, the padding
+/// around the loop and rectangle areas clear of overflow.
+pub const MAX_COORD: i32 = 1 << 30;
 
-    for line in lines {
-        if let Some((x, y)) = parse_point(line) {
-            points.push((x, y));
-        }
+pub struct DayNine;
+
+impl Solution for DayNine {
+    const DAY: u8 = 9;
+    type Input = Vec<(i32, i32)>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_points(input)?)
     }
 
-    (
-        get_largest_rectangle(points.clone()),
-        get_largest_green_rectangle(points.clone()),
-    )
+    fn part_one(points: &Self::Input) -> Result<u64> {
+        Ok(get_largest_rectangle(points))
+    }
+
+    fn part_two(points: &Self::Input) -> Result<u64> {
+        Ok(get_largest_green_rectangle(points))
+    }
 }
 
-fn get_largest_rectangle(points: Vec<(i32, i32)>) -> u64 {
+/// Parse every non-blank `x,y` line of the input.
+pub fn parse_points(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    non_blank_lines(input)
+        .map(|(line_no, line)| parse_point(line_no, line))
+        .collect()
+}
+
+pub fn solve(input: &str) -> Result<(u64, u64), ParseError> {
+    let points = parse_points(input)?;
+
+    Ok((
+        get_largest_rectangle(&points),
+        get_largest_green_rectangle(&points),
+    ))
+}
+
+pub fn get_largest_rectangle(points: &[(i32, i32)]) -> u64 {
     let mut max_area = 0u64;
     for (i, &(x1, y1)) in points.iter().enumerate() {
         for &(x2, y2) in points.iter().skip(i + 1) {
-            let width = (x1 - x2).unsigned_abs() as u64 + 1;
-            let height = (y1 - y2).unsigned_abs() as u64 + 1;
+            let width = x1.abs_diff(x2) as u64 + 1;
+            let height = y1.abs_diff(y2) as u64 + 1;
             let area = width * height;
 
             if area > max_area {
@@ -32,27 +62,42 @@ fn get_largest_rectangle(points: Vec<(i32, i32)>) -> u64 {
     max_area
 }
 
-fn parse_point(line: &str) -> Option<(i32, i32)> {
-    let mut split = line.split(',');
-    let x = split.next()?.trim().parse().ok()?;
-    let y = split.next()?.trim().parse().ok()?;
-    Some((x, y))
+/// Parse an `X,Y` tile coordinate.
+pub fn parse_point(line_no: usize, line: &str) -> Result<(i32, i32), ParseError> {
+    let (x, y) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::in_line(line_no, line, line, "a point 'X,Y'"))?;
+    Ok((
+        parse_coord(line_no, line, x.trim(), "an X coordinate")?,
+        parse_coord(line_no, line, y.trim(), "a Y coordinate")?,
+    ))
+}
+
+fn parse_coord(line_no: usize, line: &str, token: &str, expected: &str) -> Result<i32, ParseError> {
+    let value: i32 = parse_integer(line_no, line, token, expected)?;
+    if value.unsigned_abs() > MAX_COORD.unsigned_abs() {
+        return Err(ParseError::in_line(
+            line_no,
+            line,
+            token,
+            format!("{} within ±{}", expected, MAX_COORD),
+        ));
+    }
+    Ok(value)
 }
 
-fn get_largest_green_rectangle(points: Vec<(i32, i32)>) -> u64 {
-    let (xs, ys, x_to_idx, y_to_idx) = compressed::compress_coords(&points);
-    let (mut green, width, height) =
-        compressed::build_compressed_grid(&points, &xs, &ys, &x_to_idx, &y_to_idx);
+pub fn get_largest_green_rectangle(points: &[(i32, i32)]) -> u64 {
+    let (xs, ys, x_to_idx, y_to_idx) = compressed::compress_coords(points);
+    let mut green = compressed::build_compressed_grid(points, &xs, &ys, &x_to_idx, &y_to_idx);
     compressed::flood_fill_compressed(&mut green);
-    compressed::mark_red_tiles(&mut green, &points, &x_to_idx, &y_to_idx, width, height);
-    let ps = compressed::build_weighted_prefix_sum(&green, &xs, &ys, width, height);
+    compressed::mark_red_tiles(&mut green, points, &x_to_idx, &y_to_idx);
+    let ps = compressed::build_weighted_prefix_sum(&green, &xs, &ys);
 
     let mut max_area = 0u64;
     let ctx = compressed::QueryHelper {
         ps: &ps,
         x_to_idx: &x_to_idx,
         y_to_idx: &y_to_idx,
-        width,
     };
     let rect_sum = |x1: i32, y1: i32, x2: i32, y2: i32| -> u64 {
         compressed::rect_allowed_area_ctx(x1, y1, x2, y2, &ctx)
@@ -60,8 +105,8 @@ fn get_largest_green_rectangle(points: Vec<(i32, i32)>) -> u64 {
 
     for (i, &(x1, y1)) in points.iter().enumerate() {
         for &(x2, y2) in points.iter().skip(i + 1) {
-            let width_r = (x1 - x2).unsigned_abs() as u64 + 1;
-            let height_r = (y1 - y2).unsigned_abs() as u64 + 1;
+            let width_r = x1.abs_diff(x2) as u64 + 1;
+            let height_r = y1.abs_diff(y2) as u64 + 1;
             let area = width_r * height_r;
             if area <= max_area {
                 continue;
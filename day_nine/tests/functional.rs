@@ -3,9 +3,42 @@ use day_nine as lib;
 #[test]
 fn example_rectangle_area() {
     let s = include_str!("../example.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let (res, res2) = lib::solve(&lines);
+    let (res, res2) = lib::solve(s).unwrap();
     assert_eq!(res, 50);
     assert_eq!(res2, 24);
 }
+
+#[test]
+fn malformed_points_are_errors() {
+    let err = lib::solve("7,1\n11;7\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+
+    let err = lib::parse_point(3, "4, y").unwrap_err();
+    assert_eq!(
+        err,
+        aoc_common::ParseError::new(3, 4, "y", "a Y coordinate")
+    );
+
+    let err = lib::parse_point(1, "4000000000,2").unwrap_err();
+    assert_eq!(err.expected, "an X coordinate that fits in i32");
+
+    let err = lib::parse_point(1, "2,-2147483648").unwrap_err();
+    assert_eq!(err.expected, "a Y coordinate within ±1073741824");
+}
+
+#[test]
+fn extreme_coordinates_do_not_overflow() {
+    let max = lib::MAX_COORD;
+    let input = format!("{0},{0}\n{1},{0}\n{1},{1}\n{0},{1}\n", -max, max);
+    let side = 2 * max as u64 + 1;
+    assert_eq!(lib::solve(&input).unwrap(), (side * side, side * side));
+}
+
+#[test]
+fn loop_concave_at_the_centre_is_filled() {
+    // A fill started from the compressed grid's centre cell used to miss
+    // the inside of this loop and only allow a 2x2 rectangle.
+    let (_, res2) = lib::solve("1,1\n1,3\n2,3\n2,4\n3,4\n3,1\n").unwrap();
+    assert_eq!(res2, 9);
+}
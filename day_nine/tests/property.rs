@@ -0,0 +1,108 @@
+use proptest::prelude::*;
+
+/// A closed loop of red tiles shaped like a skyline: a flat base with a
+/// column of each height standing on it, one after another.
+fn skyline(widths: &[i32], heights: &[i32]) -> Vec<(i32, i32)> {
+    let base = 1;
+    let mut x = 1;
+    let mut points = vec![(x, base)];
+    for (&w, &h) in widths.iter().zip(heights) {
+        points.push((x, base + h));
+        x += w;
+        points.push((x, base + h));
+    }
+    points.push((x, base));
+    points
+}
+
+/// Tiles inside or on the loop, found by flood filling the outside of a plain
+/// tile grid with a one tile margin.
+fn green_tiles(points: &[(i32, i32)]) -> Vec<Vec<bool>> {
+    let w = points.iter().map(|p| p.0).max().unwrap() as usize + 2;
+    let h = points.iter().map(|p| p.1).max().unwrap() as usize + 2;
+    let mut wall = vec![vec![false; w]; h];
+    for (i, &(x1, y1)) in points.iter().enumerate() {
+        let (x2, y2) = points[(i + 1) % points.len()];
+        for x in x1.min(x2)..=x1.max(x2) {
+            for y in y1.min(y2)..=y1.max(y2) {
+                wall[y as usize][x as usize] = true;
+            }
+        }
+    }
+
+    let mut outside = vec![vec![false; w]; h];
+    let mut stack = vec![(0usize, 0usize)];
+    outside[0][0] = true;
+    while let Some((x, y)) = stack.pop() {
+        let next = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in next {
+            if nx < w && ny < h && !wall[ny][nx] && !outside[ny][nx] {
+                outside[ny][nx] = true;
+                stack.push((nx, ny));
+            }
+        }
+    }
+    outside
+        .iter()
+        .map(|row| row.iter().map(|&o| !o).collect())
+        .collect()
+}
+
+/// Largest rectangle with red corners, and the largest whose every tile is green.
+fn naive(points: &[(i32, i32)]) -> (u64, u64) {
+    let green = green_tiles(points);
+    let (mut any, mut all_green) = (0, 0);
+    for (i, &(x1, y1)) in points.iter().enumerate() {
+        for &(x2, y2) in &points[i + 1..] {
+            let area = u64::from(x1.abs_diff(x2) + 1) * u64::from(y1.abs_diff(y2) + 1);
+            any = any.max(area);
+            let inside = (y1.min(y2)..=y1.max(y2))
+                .all(|y| (x1.min(x2)..=x1.max(x2)).all(|x| green[y as usize][x as usize]));
+            if inside {
+                all_green = all_green.max(area);
+            }
+        }
+    }
+    (any, all_green)
+}
+
+fn loops() -> impl Strategy<Value = Vec<(i32, i32)>> {
+    (1usize..6)
+        .prop_flat_map(|columns| {
+            (
+                prop::collection::vec(1i32..5, columns),
+                prop::collection::vec(1i32..12, columns),
+                any::<bool>(),
+                any::<bool>(),
+                0..2 * columns + 2,
+            )
+        })
+        .prop_filter(
+            "neighbouring columns differ in height",
+            |(_, heights, ..)| heights.windows(2).all(|w| w[0] != w[1]),
+        )
+        .prop_map(|(widths, heights, transpose, reverse, start)| {
+            let mut points = skyline(&widths, &heights);
+            if transpose {
+                points.iter_mut().for_each(|p| *p = (p.1, p.0));
+            }
+            if reverse {
+                points.reverse();
+            }
+            points.rotate_left(start);
+            points
+        })
+}
+
+proptest! {
+    #[test]
+    fn matches_naive_tile_grid(points in loops()) {
+        let input: String = points.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+        prop_assert_eq!(day_nine::solve(&input).unwrap(), naive(&points));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::DialError;
+use crate::rotation::{Direction, Rotation};
+
+/// What one rotation did: where the dial ended up and how many times it
+/// pointed at 0 along the way, counting the final position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub rotation: Rotation,
+    pub position: u64,
+    pub zeros: u64,
+}
+
+/// How often a run of rotations brought the dial to `target`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetHits {
+    pub target: u64,
+    /// Rotations that ended on the target.
+    pub landed: u64,
+    /// Clicks that pointed at the target, including those that ended a
+    /// rotation; for target 0 this is part two's count.
+    pub passed: u64,
+}
+
+/// A dial numbered `0..size` that wraps around in both directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+}
+
+impl Default for Dial {
+    /// The puzzle's dial: 100 positions, starting at 50.
+    fn default() -> Self {
+        Dial {
+            size: 100,
+            position: 50,
+        }
+    }
+}
+
+impl Dial {
+    /// A dial of `size` positions pointing at `start`. Fails if `size` is
+    /// zero or `start` is not below `size`.
+    pub fn new(size: u64, start: u64) -> Result<Self, DialError> {
+        if size == 0 {
+            return Err(DialError::NoPositions);
+        }
+        if start >= size {
+            return Err(DialError::StartOffDial { start, size });
+        }
+        Ok(Dial {
+            size,
+            position: start,
+        })
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// How many clicks of `rotation`, started from the current position,
+    /// would point the dial at `target`. O(1) however many steps it takes.
+    pub fn hits(&self, rotation: Rotation, target: u64) -> u64 {
+        let Rotation { dir, steps } = rotation;
+        let (p, size) = (self.position, self.size);
+
+        let distance = match dir {
+            Direction::Right if target >= p => target - p,
+            Direction::Right => size - (p - target),
+            Direction::Left if p >= target => p - target,
+            Direction::Left => size - (target - p),
+        };
+        // Already on the target: the next visit is a full turn away.
+        let steps_to_first_hit = if distance == 0 { size } else { distance };
+
+        match steps.checked_sub(steps_to_first_hit) {
+            Some(rest) => 1 + rest / size,
+            None => 0,
+        }
+    }
+
+    /// Turn the dial, in O(1) however many steps the rotation takes.
+    pub fn apply(&mut self, rotation: Rotation) -> Turn {
+        let Rotation { dir, steps } = rotation;
+        let zeros = self.hits(rotation, 0);
+
+        // Whole turns change nothing; the rest never goes round twice.
+        let rest = steps % self.size;
+        self.position = match dir {
+            Direction::Right if rest >= self.size - self.position => {
+                rest - (self.size - self.position)
+            }
+            Direction::Right => self.position + rest,
+            Direction::Left if rest > self.position => self.size - (rest - self.position),
+            Direction::Left => self.position - rest,
+        };
+
+        Turn {
+            rotation,
+            position: self.position,
+            zeros,
+        }
+    }
+
+    /// Apply every rotation in order, yielding each one's [`Turn`].
+    pub fn trace<I>(&mut self, rotations: I) -> impl Iterator<Item = Turn>
+    where
+        I: IntoIterator<Item = Rotation>,
+    {
+        rotations.into_iter().map(|r| self.apply(r))
+    }
+}
@@ -0,0 +1,18 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayOne;
+
+impl Generator for DayOne {
+    const SIZES: &'static [usize] = &[1_000, 10_000, 100_000, 1_000_000];
+
+    /// `size` rotations of 1 to 999 clicks in either direction.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::with_capacity(size * 5);
+        for _ in 0..size {
+            let dir = if rng.chance(0.5) { 'L' } else { 'R' };
+            input.push_str(&format!("{}{}\n", dir, rng.range(1..=999)));
+        }
+        input
+    }
+}
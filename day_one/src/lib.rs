@@ -1,13 +1,110 @@
+use std::error::Error;
+use std::fmt;
+
+use anyhow::Result;
+use aoc_common::{OverflowError, ParseError, Solution};
+
+mod dial;
+mod generator;
+mod rotation;
 mod solutions;
 
-pub fn solve_part_one(input: &str) -> i32 {
-    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-    solutions::part_one(&lines)
+pub use dial::{Dial, TargetHits, Turn};
+pub use rotation::{Direction, Rotation, parse_rotations};
+
+/// Why a dial could not be built or the rotations on it counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialError {
+    Parse(ParseError),
+    Overflow(OverflowError),
+    /// A target that is not a position on the dial.
+    OffDial {
+        target: u64,
+        size: u64,
+    },
+    /// A dial of size zero.
+    NoPositions,
+    /// A starting position that is not on the dial.
+    StartOffDial {
+        start: u64,
+        size: u64,
+    },
+}
+
+impl fmt::Display for DialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DialError::Parse(e) => e.fmt(f),
+            DialError::Overflow(e) => e.fmt(f),
+            DialError::OffDial { target, size } => {
+                write!(f, "target {} is not on a dial of {}", target, size)
+            }
+            DialError::NoPositions => write!(f, "a dial needs at least one position"),
+            DialError::StartOffDial { start, size } => {
+                write!(f, "start {} is not on a dial of {}", start, size)
+            }
+        }
+    }
+}
+
+impl Error for DialError {}
+
+impl From<ParseError> for DialError {
+    fn from(e: ParseError) -> Self {
+        DialError::Parse(e)
+    }
 }
 
-pub fn solve_part_two(input: &str) -> i32 {
-    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-    solutions::part_two(&lines)
+impl From<OverflowError> for DialError {
+    fn from(e: OverflowError) -> Self {
+        DialError::Overflow(e)
+    }
+}
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+    const DAY: u8 = 1;
+    type Input = Vec<Rotation>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_rotations(input)?)
+    }
+
+    fn part_one(rotations: &Self::Input) -> Result<u64> {
+        Ok(solutions::part_one(rotations))
+    }
+
+    fn part_two(rotations: &Self::Input) -> Result<u64> {
+        Ok(solutions::part_two(rotations)?)
+    }
+}
+
+pub fn solve_part_one(input: &str) -> Result<u64, ParseError> {
+    Ok(solutions::part_one(&parse_rotations(input)?))
+}
+
+pub fn solve_part_two(input: &str) -> Result<u64, DialError> {
+    Ok(solutions::part_two(&parse_rotations(input)?)?)
+}
+
+/// Every rotation in `input` applied to `dial`, for checking a password turn
+/// by turn or modelling other dials.
+pub fn trace(input: &str, dial: &mut Dial) -> Result<Vec<Turn>, ParseError> {
+    Ok(dial.trace(parse_rotations(input)?).collect())
+}
+
+/// How often the rotations in `input` land on and pass through each of
+/// `targets`, for exploring combinations other than 0. A target that is not a
+/// position on `dial` is an error.
+pub fn target_hits(
+    input: &str,
+    dial: &mut Dial,
+    targets: &[u64],
+) -> Result<Vec<TargetHits>, DialError> {
+    solutions::target_hits(&parse_rotations(input)?, dial, targets)
 }
 
 #[cfg(test)]
@@ -17,7 +114,159 @@ mod tests {
     #[test]
     fn smoke() {
         let input = "L50\nR50\n";
-        let _ = solve_part_one(input);
-        let _ = solve_part_two(input);
+        assert_eq!(solve_part_one(input), Ok(1));
+        assert_eq!(solve_part_two(input), Ok(1));
+    }
+
+    #[test]
+    fn rejects_bad_rotations() {
+        let err = solve_part_one("L5\nX10\n").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 1, "X", "direction 'L' or 'R'"));
+
+        let Err(DialError::Parse(err)) = solve_part_two("R5\nR\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column), (2, 2));
+
+        assert!(solve_part_one("L1x").is_err());
+
+        // The solution parses once, up front, so a bad line fails `parse`.
+        assert!(DayOne::parse("L5\nX10\n").is_err());
+        assert_eq!(DayOne::parse("L5\n\nR10\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn typos_fail_loudly() {
+        for (line, column, text, expected) in [
+            ("l5", 1, "l", "direction 'L' or 'R'"),
+            ("Left5", 2, "eft5", "a step count"),
+            ("L 5", 2, " 5", "a step count"),
+            ("L+5", 2, "+5", "a step count"),
+            ("R-5", 2, "-5", "a step count"),
+            ("R5 L5", 2, "5 L5", "a step count"),
+            ("R5;", 2, "5;", "a step count"),
+            (
+                "R18446744073709551616",
+                2,
+                "18446744073709551616",
+                "a step count that fits in u64",
+            ),
+        ] {
+            let input = format!("R1\n  {}\n", line);
+            let err = solve_part_two(&input).unwrap_err();
+            assert_eq!(
+                err,
+                DialError::Parse(ParseError::new(2, column + 2, text, expected)),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn rotations_display_as_written() {
+        for line in ["L68", "R0", "R18446744073709551615"] {
+            assert_eq!(Rotation::parse(1, line).unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn trace_lists_every_turn() {
+        let turns = trace("L68\nL30\nR48\n", &mut Dial::default()).unwrap();
+        let summary: Vec<(u64, u64)> = turns.iter().map(|t| (t.position, t.zeros)).collect();
+        assert_eq!(summary, vec![(82, 1), (52, 0), (0, 1)]);
+        assert_eq!(
+            turns[0].rotation,
+            Rotation {
+                dir: Direction::Left,
+                steps: 68
+            }
+        );
+
+        let mut small = Dial::new(10, 0).unwrap();
+        let turns = trace("R25\nL5\n", &mut small).unwrap();
+        assert_eq!((turns[0].position, turns[0].zeros), (5, 2));
+        assert_eq!((turns[1].position, turns[1].zeros), (0, 1));
+        assert_eq!(small.position(), 0);
+    }
+
+    #[test]
+    fn counts_hits_on_any_target() {
+        let input = include_str!("../example.txt");
+        let hits = target_hits(input, &mut Dial::default(), &[0, 50, 99]).unwrap();
+        assert_eq!(
+            hits[0],
+            TargetHits {
+                target: 0,
+                landed: solve_part_one(input).unwrap(),
+                passed: solve_part_two(input).unwrap(),
+            }
+        );
+        // The dial starts on 50; only leaving and coming back counts.
+        assert_eq!((hits[1].landed, hits[1].passed), (0, 4));
+        assert_eq!((hits[2].landed, hits[2].passed), (1, 6));
+    }
+
+    #[test]
+    fn targets_must_be_on_the_dial() {
+        let err = target_hits("R1", &mut Dial::default(), &[0, 100]).unwrap_err();
+        assert_eq!(
+            err,
+            DialError::OffDial {
+                target: 100,
+                size: 100
+            }
+        );
+        assert_eq!(err.to_string(), "target 100 is not on a dial of 100");
+    }
+
+    #[test]
+    fn dials_must_hold_their_start() {
+        assert_eq!(Dial::new(0, 0), Err(DialError::NoPositions));
+        let err = Dial::new(10, 10).unwrap_err();
+        assert_eq!(
+            err,
+            DialError::StartOffDial {
+                start: 10,
+                size: 10
+            }
+        );
+        assert_eq!(err.to_string(), "start 10 is not on a dial of 10");
+        assert_eq!(Dial::new(100, 50), Ok(Dial::default()));
+    }
+
+    #[test]
+    fn widest_dial_does_not_overflow() {
+        let mut dial = Dial::new(u64::MAX, u64::MAX - 1).unwrap();
+        let right = Rotation {
+            dir: Direction::Right,
+            steps: u64::MAX,
+        };
+        let turn = dial.apply(right);
+        assert_eq!((turn.position, turn.zeros), (u64::MAX - 1, 1));
+
+        let left = Rotation {
+            dir: Direction::Left,
+            steps: u64::MAX - 1,
+        };
+        assert_eq!(dial.apply(left).position, 0);
+    }
+
+    #[test]
+    fn huge_step_counts_do_not_overflow() {
+        let input = "R2147483647\nL2147483647\nL50\n";
+        assert_eq!(solve_part_one(input), Ok(1));
+        assert_eq!(solve_part_two(input), Ok(42949673));
+
+        let input = "R18446744073709551615\n".repeat(120);
+        let err = solve_part_two(&input).unwrap_err();
+        assert_eq!(
+            err,
+            DialError::Overflow(OverflowError::new::<u64>("the password after rotation 101"))
+        );
+        let err = target_hits(&input, &mut Dial::default(), &[0]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the hit count for 0 after rotation 101 does not fit in u64"
+        );
     }
 }
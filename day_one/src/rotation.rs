@@ -0,0 +1,69 @@
+use std::fmt;
+
+use aoc_common::{ParseError, non_blank_lines, parse_integer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// One instruction from the puzzle input, such as `L68`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub dir: Direction,
+    pub steps: u64,
+}
+
+impl Rotation {
+    /// Parse `line`, line `line_no` of the input, as exactly one rotation:
+    /// `L` or `R` followed by decimal digits, with nothing else on the line
+    /// but surrounding whitespace.
+    pub fn parse(line_no: usize, line: &str) -> Result<Self, ParseError> {
+        let trimmed = line.trim();
+        let dir = match trimmed.chars().next() {
+            Some('L') => Direction::Left,
+            Some('R') => Direction::Right,
+            Some(c) => {
+                return Err(ParseError::in_line(
+                    line_no,
+                    line,
+                    &trimmed[..c.len_utf8()],
+                    "direction 'L' or 'R'",
+                ));
+            }
+            None => return Err(ParseError::in_line(line_no, line, "", "a rotation")),
+        };
+
+        // `parse_integer` alone would also accept a leading `+`.
+        let steps_str = &trimmed[1..];
+        if steps_str.is_empty() || !steps_str.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::in_line(
+                line_no,
+                line,
+                steps_str,
+                "a step count",
+            ));
+        }
+        let steps = parse_integer(line_no, line, steps_str, "a step count")?;
+
+        Ok(Rotation { dir, steps })
+    }
+}
+
+/// Parse every non-blank line of `input` as a rotation.
+pub fn parse_rotations(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    non_blank_lines(input)
+        .map(|(line_no, line)| Rotation::parse(line_no, line))
+        .collect()
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dir = match self.dir {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}{}", dir, self.steps)
+    }
+}
@@ -1,64 +1,70 @@
-use aoc_common as common;
+use aoc_common::OverflowError;
 
-pub fn part_one(lines: &[String]) -> i32 {
-    let mut dial_start = 50;
-    let mut password = 0;
+use crate::DialError;
+use crate::dial::{Dial, TargetHits};
+use crate::rotation::Rotation;
 
-    for line in lines {
-        if let Ok((letter, number)) = common::split_alpha_num(line) {
-            dial_start = match letter.as_str() {
-                "L" => (dial_start - number).rem_euclid(100),
-                "R" => (dial_start + number).rem_euclid(100),
-                _ => dial_start,
-            };
+/// Hit counts for each of `targets` over `rotations`, in the order the
+/// targets were given. Costs O(targets) per rotation.
+pub fn target_hits(
+    rotations: &[Rotation],
+    dial: &mut Dial,
+    targets: &[u64],
+) -> Result<Vec<TargetHits>, DialError> {
+    if let Some(&target) = targets.iter().find(|&&t| t >= dial.size()) {
+        return Err(DialError::OffDial {
+            target,
+            size: dial.size(),
+        });
+    }
+    let mut hits: Vec<TargetHits> = targets
+        .iter()
+        .map(|&target| TargetHits {
+            target,
+            landed: 0,
+            passed: 0,
+        })
+        .collect();
 
-            if dial_start == 0 {
-                password += 1;
+    for (n, &rotation) in rotations.iter().enumerate() {
+        for h in &mut hits {
+            h.passed = h
+                .passed
+                .checked_add(dial.hits(rotation, h.target))
+                .ok_or_else(|| {
+                    OverflowError::new::<u64>(format!(
+                        "the hit count for {} after rotation {}",
+                        h.target,
+                        n + 1
+                    ))
+                })?;
+        }
+        let turn = dial.apply(rotation);
+        for h in &mut hits {
+            if turn.position == h.target {
+                h.landed += 1;
             }
-        } else {
-            eprintln!("Error parsing line: {}", line);
         }
     }
 
-    password
+    Ok(hits)
 }
 
-pub fn part_two(lines: &[String]) -> i32 {
-    let dial_size = 100;
-    let mut dial_start = 50;
-    let mut password = 0;
-
-    for line in lines {
-        if let Ok((letter, steps)) = common::split_alpha_num(line) {
-            let direction = letter.chars().next().unwrap_or('R');
-            let clockwise = direction == 'R';
-            let abs_steps = steps.abs();
-
-            let steps_to_first_zero = if dial_start == 0 {
-                dial_size
-            } else if clockwise {
-                dial_size - dial_start
-            } else {
-                dial_start
-            };
-
-            let zeros = if abs_steps >= steps_to_first_zero {
-                1 + (abs_steps - steps_to_first_zero) / dial_size
-            } else {
-                0
-            };
-
-            password += zeros;
+/// How many rotations leave the dial at 0.
+pub fn part_one(rotations: &[Rotation]) -> u64 {
+    Dial::default()
+        .trace(rotations.iter().copied())
+        .filter(|turn| turn.position == 0)
+        .count() as u64
+}
 
-            dial_start = if clockwise {
-                (dial_start + steps).rem_euclid(dial_size)
-            } else {
-                (dial_start - steps).rem_euclid(dial_size)
-            };
-        } else {
-            eprintln!("Error parsing line: {}", line);
-        }
+/// How many clicks, over all rotations, leave the dial at 0.
+pub fn part_two(rotations: &[Rotation]) -> Result<u64, OverflowError> {
+    let mut password: u64 = 0;
+    for (n, turn) in Dial::default().trace(rotations.iter().copied()).enumerate() {
+        password = password.checked_add(turn.zeros).ok_or_else(|| {
+            OverflowError::new::<u64>(format!("the password after rotation {}", n + 1))
+        })?;
     }
-
-    password
+    Ok(password)
 }
@@ -0,0 +1,103 @@
+use day_one::{Dial, Direction, Rotation};
+use proptest::prelude::*;
+
+fn render(rotations: &[(bool, u64)]) -> String {
+    rotations
+        .iter()
+        .map(|&(left, steps)| format!("{}{}\n", if left { 'L' } else { 'R' }, steps))
+        .collect()
+}
+
+/// Per target, rotations ending on it and clicks pointing at it, turning the
+/// dial one click at a time.
+fn simulate_targets(
+    size: u64,
+    start: u64,
+    targets: &[u64],
+    rotations: &[(bool, u64)],
+) -> Vec<(u64, u64)> {
+    let mut dial = start;
+    let mut counts = vec![(0, 0); targets.len()];
+    for &(left, steps) in rotations {
+        for _ in 0..steps {
+            dial = if left {
+                (dial + size - 1) % size
+            } else {
+                (dial + 1) % size
+            };
+            for (count, &t) in counts.iter_mut().zip(targets) {
+                count.1 += u64::from(dial == t);
+            }
+        }
+        for (count, &t) in counts.iter_mut().zip(targets) {
+            count.0 += u64::from(dial == t);
+        }
+    }
+    counts
+}
+
+/// Turn a dial of `size` one click at a time from `start`, giving the
+/// position after each rotation and the clicks that landed on zero.
+fn simulate(size: u64, start: u64, rotations: &[(bool, u64)]) -> Vec<(u64, u64)> {
+    let mut dial = start;
+    let mut turns = Vec::new();
+    for &(left, steps) in rotations {
+        let mut zeros = 0;
+        for _ in 0..steps {
+            dial = if left {
+                (dial + size - 1) % size
+            } else {
+                (dial + 1) % size
+            };
+            if dial == 0 {
+                zeros += 1;
+            }
+        }
+        turns.push((dial, zeros));
+    }
+    turns
+}
+
+proptest! {
+    #[test]
+    fn zero_counts_match_click_by_click(
+        rotations in prop::collection::vec((any::<bool>(), 0u64..1000), 0..50)
+    ) {
+        let input = render(&rotations);
+        let turns = simulate(100, 50, &rotations);
+        let at_end = turns.iter().filter(|&&(position, _)| position == 0).count() as u64;
+        let passed = turns.iter().map(|&(_, zeros)| zeros).sum::<u64>();
+        prop_assert_eq!(day_one::solve_part_one(&input).unwrap(), at_end);
+        prop_assert_eq!(day_one::solve_part_two(&input).unwrap(), passed);
+    }
+
+    #[test]
+    fn any_dial_matches_click_by_click(
+        (size, start) in (1u64..20).prop_flat_map(|size| (Just(size), 0..size)),
+        rotations in prop::collection::vec((any::<bool>(), 0u64..100), 0..30)
+    ) {
+        let mut dial = Dial::new(size, start).unwrap();
+        let traced: Vec<(u64, u64)> = dial
+            .trace(rotations.iter().map(|&(left, steps)| Rotation {
+                dir: if left { Direction::Left } else { Direction::Right },
+                steps,
+            }))
+            .map(|turn| (turn.position, turn.zeros))
+            .collect();
+        prop_assert_eq!(&traced, &simulate(size, start, &rotations));
+        prop_assert_eq!(dial.position(), traced.last().map_or(start, |&(p, _)| p));
+    }
+
+    #[test]
+    fn target_hits_match_click_by_click(
+        (size, start, targets) in (1u64..20).prop_flat_map(|size| {
+            (Just(size), 0..size, prop::collection::vec(0..size, 0..5))
+        }),
+        rotations in prop::collection::vec((any::<bool>(), 0u64..100), 0..30)
+    ) {
+        let input = render(&rotations);
+        let hits = day_one::target_hits(&input, &mut Dial::new(size, start).unwrap(), &targets).unwrap();
+        let counts: Vec<(u64, u64)> = hits.iter().map(|h| (h.landed, h.passed)).collect();
+        prop_assert_eq!(counts, simulate_targets(size, start, &targets, &rotations));
+    }
+}
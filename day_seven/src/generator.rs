@@ -0,0 +1,36 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DaySeven;
+
+impl Generator for DaySeven {
+    const SIZES: &'static [usize] = &[64, 128, 256, 512];
+
+    /// A manifold `size` rows tall and about as wide, with `S` centred on the
+    /// top row. As in the puzzle input, every other row holds splitters on
+    /// alternating columns of a widening triangle, about 70% of them present.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let width = size | 1;
+        let centre = width / 2;
+        let mut input = String::with_capacity(size * (width + 1));
+        for r in 0..size {
+            let mut row = vec![b'.'; width];
+            if r == 0 {
+                row[centre] = b'S';
+            } else if r % 2 == 0 {
+                let depth = r / 2;
+                for j in 0..depth {
+                    let Some(c) = (centre + 2 * j + 1).checked_sub(depth) else {
+                        continue;
+                    };
+                    if c < width && rng.chance(0.7) {
+                        row[c] = b'^';
+                    }
+                }
+            }
+            input.push_str(std::str::from_utf8(&row).expect("ASCII row"));
+            input.push('\n');
+        }
+        input
+    }
+}
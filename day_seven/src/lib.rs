@@ -1,27 +1,75 @@
+use anyhow::Result;
+use aoc_common::{Grid, OverflowError, ParseError, Solution};
 use std::collections::HashSet;
 
-fn find_start(grid: &[Vec<char>]) -> Option<(usize, usize)> {
-    for (r, row) in grid.iter().enumerate() {
-        for (c, &ch) in row.iter().enumerate() {
-            if ch == 'S' {
-                return Some((r, c));
-            }
-        }
+mod generator;
+
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    const DAY: u8 = 7;
+    type Input = Manifold;
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_manifold(input)?)
+    }
+
+    fn part_one(manifold: &Self::Input) -> Result<usize> {
+        Ok(count_splits(manifold))
+    }
+
+    fn part_two(manifold: &Self::Input) -> Result<u128> {
+        Ok(count_timelines(manifold)?)
     }
-    None
 }
 
-pub fn count_splits(grid: &[Vec<char>]) -> usize {
-    let w = grid[0].len();
+/// The manifold diagram: empty space `.`, splitters `^` and the one place
+/// `S` where the beam starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifold {
+    pub grid: Grid<char>,
+    pub start: (usize, usize),
+}
 
-    let (sr, sc) = find_start(grid).expect("No S found in grid");
+/// Parse the diagram, rejecting ragged rows, unknown cells and anything but
+/// exactly one `S`.
+pub fn parse_manifold(input: &str) -> Result<Manifold, ParseError> {
+    let grid = Grid::parse(input.lines())?;
+    let mut start = None;
+    for (pos, &cell) in grid.iter() {
+        let expected = match cell {
+            '.' | '^' => continue,
+            'S' if start.is_none() => {
+                start = Some(pos);
+                continue;
+            }
+            'S' => "a single start 'S'",
+            _ => "'.', '^' or 'S'",
+        };
+        return Err(ParseError::new(
+            pos.0 + 1,
+            pos.1 + 1,
+            cell.to_string(),
+            expected,
+        ));
+    }
+    let start = start.ok_or_else(|| ParseError::new(1, 1, "", "a start 'S'"))?;
+    Ok(Manifold { grid, start })
+}
+
+pub fn count_splits(manifold: &Manifold) -> usize {
+    let grid = &manifold.grid;
+    let w = grid.width();
+    let (sr, sc) = manifold.start;
     let mut splits = 0;
     let mut beams: HashSet<usize> = HashSet::new();
-    if sr + 1 < grid.len() {
+    if sr + 1 < grid.height() {
         beams.insert(sc);
     }
 
-    for (_r, row) in grid.iter().enumerate().skip(sr + 1) {
+    for row in grid.rows().skip(sr + 1) {
         if beams.is_empty() {
             break;
         }
@@ -45,19 +93,19 @@ pub fn count_splits(grid: &[Vec<char>]) -> usize {
     splits
 }
 
-pub fn count_timelines(grid: &[Vec<char>]) -> u128 {
-    let w = grid[0].len();
-
-    let (sr, sc) = find_start(grid).expect("No S found in grid");
+pub fn count_timelines(manifold: &Manifold) -> Result<u128, OverflowError> {
+    let grid = &manifold.grid;
+    let w = grid.width();
+    let (sr, sc) = manifold.start;
 
-    if sr + 1 >= grid.len() {
-        return 1;
+    if sr + 1 >= grid.height() {
+        return Ok(1);
     }
 
     let mut counts = vec![0u128; w];
     counts[sc] = 1;
 
-    for (_r, row) in grid.iter().enumerate().skip(sr + 1) {
+    for row in grid.rows().skip(sr + 1) {
         let mut next = vec![0u128; w];
         for (c, &cell) in row.iter().enumerate() {
             let cnt = counts[c];
@@ -66,17 +114,22 @@ pub fn count_timelines(grid: &[Vec<char>]) -> u128 {
             }
             if cell == '^' {
                 if c > 0 {
-                    next[c - 1] = next[c - 1].saturating_add(cnt);
+                    next[c - 1] = add_timelines(next[c - 1], cnt)?;
                 }
                 if c + 1 < w {
-                    next[c + 1] = next[c + 1].saturating_add(cnt);
+                    next[c + 1] = add_timelines(next[c + 1], cnt)?;
                 }
             } else {
-                next[c] = next[c].saturating_add(cnt);
+                next[c] = add_timelines(next[c], cnt)?;
             }
         }
         counts = next;
     }
 
-    counts.into_iter().sum()
+    counts.into_iter().try_fold(0, add_timelines)
+}
+
+fn add_timelines(a: u128, b: u128) -> Result<u128, OverflowError> {
+    a.checked_add(b)
+        .ok_or_else(|| OverflowError::new::<u128>("the timeline count"))
 }
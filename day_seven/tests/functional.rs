@@ -3,30 +3,58 @@ use day_seven as lib;
 
 #[test]
 fn example_counts_21() {
-    let lines = common::read_file_to_vec("example.txt").unwrap();
-    let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
-    assert_eq!(lib::count_splits(&grid), 21);
+    let input = common::read_file_to_string("example.txt").unwrap();
+    let manifold = lib::parse_manifold(&input).unwrap();
+    assert_eq!(lib::count_splits(&manifold), 21);
 }
 
 #[test]
 fn puzzle_input_result() {
-    let lines = common::read_file_to_vec("input.txt").unwrap();
-    let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
-    assert_eq!(lib::count_splits(&grid), 1649);
+    let input = common::read_file_to_string("input.txt").unwrap();
+    let manifold = lib::parse_manifold(&input).unwrap();
+    assert_eq!(lib::count_splits(&manifold), 1649);
 }
 
 #[test]
 fn example_timelines_40() {
-    let lines = common::read_file_to_vec("example.txt").unwrap();
-    let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
-    assert_eq!(lib::count_timelines(&grid), 40u128);
+    let input = common::read_file_to_string("example.txt").unwrap();
+    let manifold = lib::parse_manifold(&input).unwrap();
+    assert_eq!(lib::count_timelines(&manifold).unwrap(), 40u128);
 }
 
 #[test]
 fn puzzle_input_timelines() {
-    let lines = common::read_file_to_vec("input.txt").unwrap();
-    let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
-    let val = lib::count_timelines(&grid);
+    let input = common::read_file_to_string("input.txt").unwrap();
+    let manifold = lib::parse_manifold(&input).unwrap();
+    let val = lib::count_timelines(&manifold).unwrap();
     println!("puzzle timelines = {}", val);
     assert_eq!(val, 16937871060075u128);
 }
+
+#[test]
+fn malformed_diagrams_are_errors() {
+    let err = lib::parse_manifold("...\n.^.").unwrap_err();
+    assert_eq!(err, common::ParseError::new(1, 1, "", "a start 'S'"));
+    assert!(lib::parse_manifold("").is_err());
+
+    let err = lib::parse_manifold(".S.\n.^S").unwrap_err();
+    assert_eq!(
+        err,
+        common::ParseError::new(2, 3, "S", "a single start 'S'")
+    );
+
+    let err = lib::parse_manifold(".S.\n.v.").unwrap_err();
+    assert_eq!(err, common::ParseError::new(2, 2, "v", "'.', '^' or 'S'"));
+}
+
+#[test]
+fn too_many_timelines_is_an_error() {
+    // Each pair of rows doubles the timelines: 2^127 fits, 2^128 does not.
+    let grid = |pairs: usize| {
+        let input = format!(".S.\n{}", ".^.\n^.^\n".repeat(pairs));
+        lib::parse_manifold(&input).unwrap()
+    };
+    assert_eq!(lib::count_timelines(&grid(127)).unwrap(), 1u128 << 127);
+    let err = lib::count_timelines(&grid(128)).unwrap_err();
+    assert_eq!(err.to_string(), "the timeline count does not fit in u128");
+}
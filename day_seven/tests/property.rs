@@ -0,0 +1,68 @@
+use proptest::prelude::*;
+
+/// Follow every timeline from `(row, col)` down to the bottom, one at a time.
+fn timelines(rows: &[Vec<char>], row: usize, col: usize) -> u128 {
+    let Some(cells) = rows.get(row) else {
+        return 1;
+    };
+    if cells[col] != '^' {
+        return timelines(rows, row + 1, col);
+    }
+    let left = col
+        .checked_sub(1)
+        .map_or(0, |c| timelines(rows, row + 1, c));
+    let right = match col + 1 < cells.len() {
+        true => timelines(rows, row + 1, col + 1),
+        false => 0,
+    };
+    left + right
+}
+
+/// Columns a beam reaches on each row, and the splitters it hits on the way.
+fn splits(rows: &[Vec<char>], start: usize) -> usize {
+    let mut beams = vec![start];
+    let mut hit = 0;
+    for cells in &rows[1..] {
+        let mut next = Vec::new();
+        for &c in &beams {
+            if cells[c] == '^' {
+                hit += 1;
+                next.extend(c.checked_sub(1));
+                next.extend((c + 1 < cells.len()).then_some(c + 1));
+            } else {
+                next.push(c);
+            }
+        }
+        next.sort_unstable();
+        next.dedup();
+        beams = next;
+    }
+    hit
+}
+
+proptest! {
+    #[test]
+    fn matches_following_each_beam(
+        (start, rows) in (2usize..8).prop_flat_map(|w| {
+            (
+                0..w,
+                prop::collection::vec(
+                    prop::collection::vec(prop::bool::weighted(0.4), w),
+                    1..10,
+                ),
+            )
+        })
+    ) {
+        let mut rows: Vec<Vec<char>> = rows
+            .iter()
+            .map(|row| row.iter().map(|&s| if s { '^' } else { '.' }).collect())
+            .collect();
+        rows[0].fill('.');
+        rows[0][start] = 'S';
+        let input: String = rows.iter().map(|r| r.iter().collect::<String>() + "\n").collect();
+        let manifold = day_seven::parse_manifold(&input).unwrap();
+
+        prop_assert_eq!(day_seven::count_splits(&manifold), splits(&rows, start));
+        prop_assert_eq!(day_seven::count_timelines(&manifold).unwrap(), timelines(&rows, 1, start));
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DaySix;
+
+/// Rows of numbers above the operator row, as in the puzzle input.
+const OPERAND_ROWS: usize = 4;
+
+impl Generator for DaySix {
+    const SIZES: &'static [usize] = &[1_000, 10_000, 100_000, 1_000_000];
+
+    /// `size` problems side by side. All numbers in a problem have the same
+    /// 1 to 3 digits, so its columns read top to bottom are whole numbers too
+    /// and no answer overflows an `i64`.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut rows = vec![String::new(); OPERAND_ROWS + 1];
+        for problem in 0..size {
+            let width = rng.range(1..=3) as u32;
+            for row in &mut rows[..OPERAND_ROWS] {
+                if problem > 0 {
+                    row.push(' ');
+                }
+                let n = rng.range(10u64.pow(width - 1)..=10u64.pow(width) - 1);
+                row.push_str(&n.to_string());
+            }
+            let ops = &mut rows[OPERAND_ROWS];
+            if problem > 0 {
+                ops.push(' ');
+            }
+            ops.push(if rng.chance(0.5) { '+' } else { '*' });
+            ops.push_str(&" ".repeat(width as usize - 1));
+        }
+        rows.join("\n") + "\n"
+    }
+}
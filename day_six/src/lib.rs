@@ -1,3 +1,63 @@
+use std::error::Error;
+use std::fmt;
+
+use anyhow::Result;
+use aoc_common::{OverflowError, ParseError, Solution};
+
+use crate::worksheet::{Problem, Worksheet};
+
+mod generator;
 pub mod part_one;
 pub mod part_two;
 pub mod worksheet;
+
+/// Why a worksheet's grand total could not be worked out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorksheetError {
+    Parse(ParseError),
+    Overflow(OverflowError),
+}
+
+impl fmt::Display for WorksheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorksheetError::Parse(e) => e.fmt(f),
+            WorksheetError::Overflow(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for WorksheetError {}
+
+impl From<ParseError> for WorksheetError {
+    fn from(e: ParseError) -> Self {
+        WorksheetError::Parse(e)
+    }
+}
+
+impl From<OverflowError> for WorksheetError {
+    fn from(e: OverflowError) -> Self {
+        WorksheetError::Overflow(e)
+    }
+}
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+    const DAY: u8 = 6;
+    type Input = Vec<Problem>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Worksheet::new(input).problems()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<i64> {
+        Ok(part_one::total(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i64> {
+        Ok(part_two::total(input)?)
+    }
+}
@@ -1,16 +1,11 @@
-use crate::worksheet::{LeftToRight, Worksheet};
-use anyhow::Result;
+use crate::WorksheetError;
+use crate::worksheet::{LeftToRight, Problem, Worksheet, grand_total};
 
-pub fn solve(lines: &mut [String]) -> Result<i64> {
-    let ws = Worksheet::from_mut(lines);
-    let groups = ws.column_groups();
-
-    let mut grand_total = 0_i64;
-
-    for group in groups {
-        let problem = group.parse_problem(&LeftToRight)?;
-        grand_total += problem.result();
-    }
+pub fn solve(input: &str) -> Result<i64, WorksheetError> {
+    total(&Worksheet::new(input).problems()?)
+}
 
-    Ok(grand_total)
+/// The grand total with each row of a problem read as one number.
+pub fn total(problems: &[Problem]) -> Result<i64, WorksheetError> {
+    grand_total(problems, &LeftToRight)
 }
@@ -1,16 +1,12 @@
-use crate::worksheet::{RightToLeft, Worksheet};
-use anyhow::Result;
+use crate::WorksheetError;
+use crate::worksheet::{Problem, RightToLeft, Worksheet, grand_total};
 
-pub fn solve(lines: &mut [String]) -> Result<i64> {
-    let ws = Worksheet::from_mut(lines);
-    let groups = ws.column_groups();
-
-    let mut grand_total = 0_i64;
-
-    for group in groups {
-        let problem = group.parse_problem(&RightToLeft)?;
-        grand_total += problem.result();
-    }
+pub fn solve(input: &str) -> Result<i64, WorksheetError> {
+    total(&Worksheet::new(input).problems()?)
+}
 
-    Ok(grand_total)
+/// The grand total with each column of a problem, right to left, read as one
+/// number.
+pub fn total(problems: &[Problem]) -> Result<i64, WorksheetError> {
+    grand_total(problems, &RightToLeft)
 }
@@ -1,107 +1,196 @@
-use anyhow::{Result, anyhow};
+use aoc_common::{Grid, OverflowError, ParseError};
 
-pub struct Worksheet {
-    pub lines: Vec<String>,
+use crate::WorksheetError;
+
+/// The puzzle input viewed as a grid of bytes. Lines are borrowed as-is;
+/// short lines read as if padded with spaces to `width`.
+pub struct Worksheet<'a> {
+    pub lines: Vec<&'a str>,
     pub width: usize,
 }
 
-impl Worksheet {
-    /// Normalize the given lines in-place (pads them) and return a Worksheet
-    /// containing a clone of the normalized lines. This preserves the caller's
-    /// buffer mutation behavior while keeping Worksheet ownership local.
-    pub fn from_mut(lines: &mut [String]) -> Self {
+impl<'a> Worksheet<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let lines: Vec<&str> = input.lines().collect();
         let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
-        for l in lines.iter_mut() {
-            if l.len() < width {
-                l.push_str(&" ".repeat(width - l.len()));
-            }
-        }
-        Worksheet {
-            lines: lines.to_vec(),
-            width,
+        Worksheet { lines, width }
+    }
+
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The byte at (`row`, `col`), or a space past the end of a short line.
+    pub fn cell(&self, row: usize, col: usize) -> u8 {
+        self.lines[row].as_bytes().get(col).copied().unwrap_or(b' ')
+    }
+
+    fn is_blank_column(&self, col: usize) -> bool {
+        (0..self.height()).all(|r| self.cell(r, col) == b' ')
+    }
+
+    /// Error for the character at (`row`, `col`), or for the padding blank
+    /// at that column past the end of a short line.
+    fn error_at(&self, row: usize, col: usize, expected: &str) -> ParseError {
+        let line = self.lines[row];
+        if col >= line.len() {
+            let column = line.chars().count() + (col - line.len()) + 1;
+            return ParseError::new(row + 1, column, " ", expected);
         }
+        let start = (0..=col)
+            .rev()
+            .find(|&i| line.is_char_boundary(i))
+            .unwrap_or(0);
+        let len = line[start..].chars().next().map_or(0, char::len_utf8);
+        ParseError::in_line(row + 1, line, &line[start..start + len], expected)
     }
 
-    pub fn column_groups(&self) -> Vec<ColumnGroup> {
+    /// Every problem on the worksheet, left to right.
+    pub fn problems(&self) -> Result<Vec<Problem>, ParseError> {
+        self.column_groups()
+            .iter()
+            .map(ColumnGroup::parse_problem)
+            .collect()
+    }
+
+    pub fn column_groups(&self) -> Vec<ColumnGroup<'_, 'a>> {
         let mut groups = Vec::new();
-        let mut current: Vec<Vec<char>> = Vec::new();
+        let mut start = None;
 
         for col in 0..self.width {
-            let col_chars: Vec<char> = self
-                .lines
-                .iter()
-                .map(|l| l.chars().nth(col).unwrap())
-                .collect();
-
-            let is_empty = col_chars.iter().all(|c| *c == ' ');
-
-            if is_empty {
-                if !current.is_empty() {
-                    groups.push(ColumnGroup { cols: current });
-                    current = Vec::new();
+            match (self.is_blank_column(col), start) {
+                (true, Some(s)) => {
+                    groups.push(ColumnGroup {
+                        ws: self,
+                        start: s,
+                        end: col,
+                    });
+                    start = None;
                 }
-            } else {
-                current.push(col_chars);
+                (false, None) => start = Some(col),
+                _ => {}
             }
         }
 
-        if !current.is_empty() {
-            groups.push(ColumnGroup { cols: current });
+        if let Some(s) = start {
+            groups.push(ColumnGroup {
+                ws: self,
+                start: s,
+                end: self.width,
+            });
         }
 
         groups
     }
 }
 
-pub struct ColumnGroup {
-    pub cols: Vec<Vec<char>>,
+/// A run of adjacent non-blank columns, `start..end`, holding one problem.
+pub struct ColumnGroup<'w, 'a> {
+    pub ws: &'w Worksheet<'a>,
+    pub start: usize,
+    pub end: usize,
 }
 
-impl ColumnGroup {
-    pub fn to_rows(&self) -> Vec<String> {
-        if self.cols.is_empty() {
-            return vec![];
+impl ColumnGroup<'_, '_> {
+    /// Bytes of `row` within this group, left to right.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = u8> + '_ {
+        (self.start..self.end).map(move |c| self.ws.cell(row, c))
+    }
+
+    pub fn width(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// The group's operator and the digits above it. Which way the digits
+    /// form numbers is left to a [`ParseStrategy`].
+    pub fn parse_problem(&self) -> Result<Problem, ParseError> {
+        // A group has a non-blank column, so the worksheet has a row.
+        let bottom = self.ws.height() - 1;
+        let mut op = None;
+        for (c, b) in (self.start..).zip(self.row(bottom)) {
+            op = match (b, op) {
+                (b' ', op) => op,
+                (b'+' | b'*', None) => Some(b as char),
+                (b'+' | b'*', Some(_)) => {
+                    return Err(self.ws.error_at(bottom, c, "one operator per problem"));
+                }
+                _ => return Err(self.ws.error_at(bottom, c, "'+' or '*'")),
+            };
         }
-        let height = self.cols[0].len();
-        let mut rows = vec![String::new(); height];
-        for (r, row) in rows.iter_mut().enumerate().take(height) {
-            for col in &self.cols {
-                row.push(col[r]);
+        let op = op.ok_or_else(|| {
+            self.ws
+                .error_at(bottom, self.start, "an operator under the problem")
+        })?;
+
+        let mut digits = Grid::new(self.width(), bottom, None);
+        for r in 0..bottom {
+            for (c, b) in self.row(r).enumerate() {
+                digits[(r, c)] = match b {
+                    b' ' => None,
+                    b'0'..=b'9' => Some(b - b'0'),
+                    _ => return Err(self.ws.error_at(r, self.start + c, "a digit")),
+                };
             }
         }
-        rows
+        Ok(Problem {
+            column: self.start,
+            digits,
+            op,
+        })
     }
+}
+
+/// Why a run of digits is not one number, with the index of the digit at
+/// fault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberError {
+    /// A digit after the blank that ended the number.
+    Gap(usize),
+    /// The digit that takes the number past `i64`.
+    TooLarge(usize),
+}
+
+/// Parse a run of digits padded with blanks (`None`), returning `None` if it
+/// is all blank.
+pub fn parse_number(
+    digits: impl IntoIterator<Item = Option<u8>>,
+) -> Result<Option<i64>, NumberError> {
+    let mut value: Option<i64> = None;
+    let mut ended = false;
 
-    pub fn parse_problem<S: ParseStrategy>(&self, strategy: &S) -> Result<Problem> {
-        let rows = self.to_rows();
-        let bottom = rows.last().unwrap().trim();
-        if bottom != "+" && bottom != "*" {
-            return Err(anyhow!("Invalid operator: {}", bottom));
+    for (i, d) in digits.into_iter().enumerate() {
+        match d {
+            None => ended = value.is_some(),
+            Some(_) if ended => return Err(NumberError::Gap(i)),
+            Some(d) => {
+                value = value
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(i64::from(d)));
+                if value.is_none() {
+                    return Err(NumberError::TooLarge(i));
+                }
+            }
         }
-        let op = bottom.chars().next().unwrap();
-        let numbers = strategy.parse_numbers(self)?;
-        Ok(Problem { numbers, op })
     }
+
+    Ok(value)
 }
 
 pub trait ParseStrategy {
-    fn parse_numbers(&self, cg: &ColumnGroup) -> Result<Vec<i64>>;
+    fn parse_numbers(&self, problem: &Problem) -> Result<Vec<i64>, ParseError>;
 }
 
 pub struct LeftToRight;
 pub struct RightToLeft;
 
 impl ParseStrategy for LeftToRight {
-    fn parse_numbers(&self, cg: &ColumnGroup) -> Result<Vec<i64>> {
-        let rows = cg.to_rows();
-        if rows.is_empty() {
-            return Ok(vec![]);
-        }
+    fn parse_numbers(&self, problem: &Problem) -> Result<Vec<i64>, ParseError> {
         let mut out = Vec::new();
-        for row in rows[..rows.len() - 1].iter() {
-            let t = row.trim();
-            if !t.is_empty() {
-                out.push(t.parse::<i64>()?);
+        for (r, row) in problem.digits.rows().enumerate() {
+            match parse_number(row.iter().copied()) {
+                Ok(n) => out.extend(n),
+                Err(e) => return Err(problem.number_error(e, |c| (r, c), "row")),
             }
         }
         Ok(out)
@@ -109,37 +198,68 @@ impl ParseStrategy for LeftToRight {
 }
 
 impl ParseStrategy for RightToLeft {
-    fn parse_numbers(&self, cg: &ColumnGroup) -> Result<Vec<i64>> {
-        if cg.cols.is_empty() {
-            return Ok(vec![]);
-        }
-        let height = cg.cols[0].len();
+    fn parse_numbers(&self, problem: &Problem) -> Result<Vec<i64>, ParseError> {
         let mut out = Vec::new();
-        for c in (0..cg.cols.len()).rev() {
-            let mut s = String::new();
-            for r in 0..(height - 1) {
-                s.push(cg.cols[c][r]);
-            }
-            let t = s.trim();
-            if !t.is_empty() {
-                out.push(t.parse::<i64>()?);
+        for c in (0..problem.digits.width()).rev() {
+            match parse_number(problem.digits.column(c).copied()) {
+                Ok(n) => out.extend(n),
+                Err(e) => return Err(problem.number_error(e, |r| (r, c), "column")),
             }
         }
         Ok(out)
     }
 }
 
+/// One problem: the digits above its operator, `None` where a cell is blank.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Problem {
-    pub numbers: Vec<i64>,
+    /// Worksheet column of the problem's left edge, counting from 0.
+    pub column: usize,
+    pub digits: Grid<Option<u8>>,
     pub op: char,
 }
 
 impl Problem {
-    pub fn result(&self) -> i64 {
-        match self.op {
-            '+' => self.numbers.iter().sum(),
-            '*' => self.numbers.iter().product(),
+    /// The problem's answer with its numbers read by `strategy`.
+    pub fn result<S: ParseStrategy>(&self, strategy: &S) -> Result<i64, WorksheetError> {
+        let numbers = strategy.parse_numbers(self)?;
+        let mut numbers = numbers.iter().copied();
+        let result = match self.op {
+            '+' => numbers.try_fold(0i64, i64::checked_add),
+            '*' => numbers.try_fold(1i64, i64::checked_mul),
             _ => unreachable!("only + or * expected"),
-        }
+        };
+        let what = format!("the answer to the problem at column {}", self.column + 1);
+        Ok(result.ok_or_else(|| OverflowError::new::<i64>(what))?)
     }
+
+    /// Error for a number read along a `line` of cells; `cell` gives the
+    /// position of the run's `i`th cell.
+    fn number_error(
+        &self,
+        error: NumberError,
+        cell: impl Fn(usize) -> (usize, usize),
+        line: &str,
+    ) -> ParseError {
+        let (i, expected) = match error {
+            NumberError::Gap(i) => (i, format!("one number per {}", line)),
+            NumberError::TooLarge(i) => (i, "a number that fits in i64".to_string()),
+        };
+        let (r, c) = cell(i);
+        let digit = self.digits[(r, c)].map_or(String::new(), |d| d.to_string());
+        // Parsing checked every cell is a digit or a space, so columns count
+        // bytes and characters alike.
+        ParseError::new(r + 1, self.column + c + 1, digit, expected)
+    }
+}
+
+/// The sum of every problem's answer, reading numbers with `strategy`.
+pub fn grand_total<S: ParseStrategy>(
+    problems: &[Problem],
+    strategy: &S,
+) -> Result<i64, WorksheetError> {
+    problems.iter().try_fold(0_i64, |total, problem| {
+        let sum = total.checked_add(problem.result(strategy)?);
+        Ok(sum.ok_or_else(|| OverflowError::new::<i64>("the grand total"))?)
+    })
 }
@@ -3,8 +3,7 @@ use day_six as lib;
 fn example_part_two() {
     // load example.txt bundled with the crate
     let s = include_str!("../example.txt");
-    let mut lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let res = lib::part_two::solve(&mut lines).expect("part two failed");
+    let res = lib::part_two::solve(s).expect("part two failed");
     assert_eq!(res, 3263827);
 }
@@ -0,0 +1,92 @@
+use proptest::prelude::*;
+
+/// One problem: its operands, the operator, and whether the operands are
+/// left aligned in their column (otherwise right aligned).
+type Problem = (Vec<u32>, bool, bool);
+
+fn render(problems: &[Problem]) -> String {
+    let rows = problems[0].0.len();
+    let mut lines = vec![Vec::new(); rows + 1];
+    for (numbers, add, left) in problems {
+        let width = numbers.iter().map(|n| n.to_string().len()).max().unwrap();
+        for (line, n) in lines.iter_mut().zip(numbers) {
+            line.push(match left {
+                true => format!("{:<width$}", n),
+                false => format!("{:>width$}", n),
+            });
+        }
+        let op = if *add { "+" } else { "*" };
+        lines[rows].push(format!("{:<width$}", op));
+    }
+    lines.iter().map(|l| l.join(" ") + "\n").collect()
+}
+
+fn apply(add: bool, numbers: impl Iterator<Item = i64>) -> i64 {
+    if add {
+        numbers.sum()
+    } else {
+        numbers.product()
+    }
+}
+
+/// Read each problem's columns top to bottom, or `None` if some column has a
+/// gap between its digits.
+fn by_columns(problems: &[Problem]) -> Option<i64> {
+    let mut total = 0;
+    for (numbers, add, left) in problems {
+        let width = numbers.iter().map(|n| n.to_string().len()).max().unwrap();
+        let cells: Vec<Vec<char>> = numbers
+            .iter()
+            .map(|n| match left {
+                true => format!("{:<width$}", n),
+                false => format!("{:>width$}", n),
+            })
+            .map(|s| s.chars().collect())
+            .collect();
+        let mut columns = Vec::new();
+        for c in 0..width {
+            let column: String = cells.iter().map(|row| row[c]).collect();
+            let digits = column.trim();
+            if digits.contains(' ') {
+                return None;
+            }
+            columns.push(digits.parse::<i64>().unwrap());
+        }
+        total += apply(*add, columns.into_iter());
+    }
+    Some(total)
+}
+
+fn problems() -> impl Strategy<Value = Vec<Problem>> {
+    (1usize..=4).prop_flat_map(|rows| {
+        prop::collection::vec(
+            (
+                prop::collection::vec(0u32..1000, rows),
+                any::<bool>(),
+                any::<bool>(),
+            ),
+            1..8,
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn rows_match_direct_evaluation(problems in problems()) {
+        let input = render(&problems);
+        let expected: i64 = problems
+            .iter()
+            .map(|(numbers, add, _)| apply(*add, numbers.iter().map(|&n| i64::from(n))))
+            .sum();
+        prop_assert_eq!(day_six::part_one::solve(&input).unwrap(), expected);
+    }
+
+    #[test]
+    fn columns_match_direct_reading(problems in problems()) {
+        let input = render(&problems);
+        match by_columns(&problems) {
+            Some(expected) => prop_assert_eq!(day_six::part_two::solve(&input).unwrap(), expected),
+            None => prop_assert!(day_six::part_two::solve(&input).is_err()),
+        }
+    }
+}
@@ -2,20 +2,111 @@ use day_six as lib;
 #[test]
 fn grouping_and_parse_right_to_left() {
     let s = include_str!("../example.txt");
-    let mut lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let ws = lib::worksheet::Worksheet::from_mut(&mut lines);
+    let ws = lib::worksheet::Worksheet::new(s);
     let groups = ws.column_groups();
     assert_eq!(groups.len(), 4);
 
     let mut results = Vec::new();
     for g in groups {
-        let p = g
-            .parse_problem(&lib::worksheet::RightToLeft)
-            .expect("parse failed");
-        results.push(p.result());
+        let p = g.parse_problem().expect("parse failed");
+        results.push(p.result(&lib::worksheet::RightToLeft).unwrap());
     }
 
     assert_eq!(results, vec![8544, 625, 3253600, 1058]);
     assert_eq!(results.iter().sum::<i64>(), 3263827);
 }
+
+#[test]
+fn short_lines_read_as_padded() {
+    use lib::worksheet::ParseStrategy;
+
+    let ws = lib::worksheet::Worksheet::new("12 3\n4\n+  *");
+    assert_eq!(ws.width, 4);
+    assert_eq!(ws.cell(1, 3), b' ');
+
+    let groups = ws.column_groups();
+    assert_eq!(groups.len(), 2);
+    let p = groups[0].parse_problem().unwrap();
+    assert_eq!(p.digits.row(1), [Some(4), None]);
+    let numbers = lib::worksheet::LeftToRight.parse_numbers(&p);
+    assert_eq!(numbers.unwrap(), vec![12, 4]);
+}
+
+#[test]
+fn parse_number_rejects_split_digits() {
+    use lib::worksheet::parse_number;
+    assert_eq!(
+        parse_number([None, None, Some(4), Some(2), None]).unwrap(),
+        Some(42)
+    );
+    assert_eq!(parse_number([None, None, None]).unwrap(), None);
+    assert_eq!(
+        parse_number([Some(4), None, Some(2)]),
+        Err(lib::worksheet::NumberError::Gap(2))
+    );
+}
+
+#[test]
+fn parse_errors_point_at_the_cell() {
+    use aoc_common::ParseError;
+    let problems = |s| lib::worksheet::Worksheet::new(s).problems();
+
+    let err = problems("12 3x\n4  5\n*  + \n").unwrap_err();
+    assert_eq!(err, ParseError::new(1, 5, "x", "a digit"));
+
+    let err = problems("12 3\n4  5\n-  + \n").unwrap_err();
+    assert_eq!(err, ParseError::new(3, 1, "-", "'+' or '*'"));
+
+    let err = problems("12 3\n4  5\n** + \n").unwrap_err();
+    assert_eq!(err, ParseError::new(3, 2, "*", "one operator per problem"));
+
+    let err = problems("12 3\n4  5\n   +\n").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::new(3, 1, " ", "an operator under the problem")
+    );
+}
+
+#[test]
+fn split_numbers_point_at_the_digit() {
+    use aoc_common::ParseError;
+    use lib::WorksheetError;
+
+    // Read by rows, "1 2" is two numbers in one row; "345" keeps the
+    // blank from splitting the problem in two.
+    let err = lib::part_one::solve("1 2\n345\n+  \n").unwrap_err();
+    let parse = ParseError::new(1, 3, "2", "one number per row");
+    assert_eq!(err, WorksheetError::Parse(parse));
+
+    // Read by columns, the first column is "1", a blank, then "3".
+    let err = lib::part_two::solve("1 \n 2\n3 \n* \n").unwrap_err();
+    let parse = ParseError::new(3, 1, "3", "one number per column");
+    assert_eq!(err, WorksheetError::Parse(parse));
+}
+
+#[test]
+fn input_is_parsed_once() {
+    use aoc_common::Solution;
+    // A stray letter fails `parse`, before either part runs.
+    assert!(lib::DaySix::parse("12 3x\n4  5\n*  + \n").is_err());
+
+    let problems = lib::DaySix::parse(include_str!("../example.txt")).unwrap();
+    assert_eq!(problems.len(), 4);
+    assert_eq!(lib::DaySix::part_two(&problems).unwrap(), 3263827);
+}
+
+#[test]
+fn overflowing_results_are_errors() {
+    let product = "9999999999\n9999999999\n*         \n";
+    assert!(lib::part_one::solve(product).is_err());
+
+    let sum = "9223372036854775807 1\n+                   +\n";
+    assert!(lib::part_one::solve(sum).is_err());
+
+    let err = lib::part_one::solve(product).unwrap_err().to_string();
+    assert_eq!(
+        err,
+        "the answer to the problem at column 1 does not fit in i64"
+    );
+}
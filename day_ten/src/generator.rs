@@ -0,0 +1,51 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayTen;
+
+impl Generator for DayTen {
+    const SIZES: &'static [usize] = &[25, 50, 100, 200];
+
+    /// `size` machines of 4 to 10 lights and 3 to 10 buttons. The light
+    /// pattern and joltage targets come from pressing the buttons at random,
+    /// so both are always reachable.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            let lights = rng.range(4..=10) as usize;
+            let buttons: Vec<Vec<usize>> = (0..rng.range(3..=10))
+                .map(|_| {
+                    let mut wiring: Vec<usize> = (0..lights).collect();
+                    rng.shuffle(&mut wiring);
+                    wiring.truncate(rng.range(1..=lights as u64) as usize);
+                    wiring.sort_unstable();
+                    wiring
+                })
+                .collect();
+
+            let mut pattern = vec!['.'; lights];
+            let mut joltage = vec![0u64; lights];
+            for wiring in &buttons {
+                let toggled = rng.chance(0.5);
+                let presses = rng.range(0..=15);
+                for &i in wiring {
+                    if toggled {
+                        pattern[i] = if pattern[i] == '.' { '#' } else { '.' };
+                    }
+                    joltage[i] += presses;
+                }
+            }
+
+            input.push('[');
+            input.extend(&pattern);
+            input.push(']');
+            for wiring in &buttons {
+                let indices: Vec<String> = wiring.iter().map(usize::to_string).collect();
+                input.push_str(&format!(" ({})", indices.join(",")));
+            }
+            let targets: Vec<String> = joltage.iter().map(u64::to_string).collect();
+            input.push_str(&format!(" {{{}}}\n", targets.join(",")));
+        }
+        input
+    }
+}
@@ -1,66 +1,49 @@
+use crate::{Machine, MachineError, parse_machines};
 use std::cmp::min;
 
-#[derive(Debug)]
-struct Machine {
-    target: Vec<u8>,
-    buttons: Vec<Vec<u8>>,
-}
-
-fn xor_vec(a: &[u8], b: &[u8]) -> Vec<u8> {
-    a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect()
-}
-
-fn parse_machine(line: &str) -> Machine {
-    let light_part = line.split(']').next().unwrap().trim_start_matches('[');
-    let target = light_part
-        .chars()
-        .map(|c| if c == '#' { 1 } else { 0 })
-        .collect::<Vec<_>>();
-
-    let mut buttons = vec![];
-    for chunk in line.split('(').skip(1) {
-        let button_str = chunk.split(')').next().unwrap();
-        let indices = button_str
-            .split(',')
-            .filter_map(|x| x.parse::<usize>().ok())
-            .collect::<Vec<_>>();
-
-        let mut button_vec = vec![0; target.len()];
-        for idx in indices {
-            button_vec[idx] = 1;
-        }
-        buttons.push(button_vec);
-    }
+/// Most buttons a machine may have; part one tries every subset of them.
+pub const MAX_BUTTONS: usize = 16;
 
-    Machine { target, buttons }
-}
-
-fn min_presses(machine: &Machine) -> usize {
+/// Fewest presses that light exactly the target pattern, or `None` if no
+/// combination of buttons does.
+///
+/// Subsets are visited in Gray code order, so each step presses or releases
+/// a single button.
+fn min_presses(machine: &Machine) -> Option<usize> {
     let n = machine.buttons.len();
-    let mut best = usize::MAX;
+    let mut state = vec![false; machine.lights.len()];
+    let mut best = (state == machine.lights).then_some(0);
 
-    for mask in 0..(1 << n) {
-        let mut state = vec![0; machine.target.len()];
-        let mut presses = 0;
-
-        for i in 0..n {
-            if (mask & (1 << i)) != 0 {
-                state = xor_vec(&state, &machine.buttons[i]);
-                presses += 1;
-            }
+    for step in 1..(1u32 << n) {
+        for &light in &machine.buttons[step.trailing_zeros() as usize] {
+            state[light] = !state[light];
         }
 
-        if state == machine.target {
-            best = min(best, presses);
+        if state == machine.lights {
+            let presses = (step ^ (step >> 1)).count_ones() as usize;
+            best = Some(best.map_or(presses, |b| min(b, presses)));
         }
     }
 
     best
 }
 
-pub fn solve(lines: &[String]) -> usize {
-    let machines = lines.iter().map(|l| parse_machine(l)).collect::<Vec<_>>();
-    let total: usize = machines.iter().map(min_presses).sum();
+pub fn solve(input: &str) -> Result<usize, MachineError> {
+    total(&parse_machines(input)?)
+}
+
+/// Fewest presses to light every machine's pattern, added up.
+pub fn total(machines: &[Machine]) -> Result<usize, MachineError> {
+    let mut total = 0;
+    for machine in machines {
+        if machine.buttons.len() > MAX_BUTTONS {
+            return Err(MachineError::TooManyButtons {
+                line: machine.line,
+                buttons: machine.buttons.len(),
+            });
+        }
+        total += min_presses(machine).ok_or(MachineError::Unreachable { line: machine.line })?;
+    }
 
-    total
+    Ok(total)
 }
@@ -1,36 +1,11 @@
+use crate::{Machine, MachineError, parse_machines};
+use aoc_common::OverflowError;
 use good_lp::*;
 
-#[derive(Debug)]
-struct Machine {
-    target: Vec<u32>,
-    buttons: Vec<Vec<usize>>,
-}
-
-fn parse_machine(line: &str) -> Machine {
-    let start = line.rfind('{').expect("missing {");
-    let end = line.rfind('}').expect("missing }");
-    let joltage_text = &line[start + 1..end];
-
-    let target = joltage_text
-        .split(',')
-        .map(|x| x.trim().parse::<u32>().expect("bad number"))
-        .collect::<Vec<_>>();
-
-    let mut buttons = vec![];
-    for chunk in line.split('(').skip(1) {
-        let inside = chunk.split(')').next().unwrap();
-        let indices = inside
-            .split(',')
-            .filter_map(|x| x.parse::<usize>().ok())
-            .collect::<Vec<_>>();
-        buttons.push(indices);
-    }
-
-    Machine { target, buttons }
-}
-
-fn min_presses_ilp(machine: &Machine) -> u32 {
-    let num_counters = machine.target.len();
+/// Fewest presses that bring every counter to its target, or `None` if no
+/// combination of presses does.
+fn min_presses_ilp(machine: &Machine) -> Option<u64> {
+    let num_counters = machine.joltages.len();
     let num_buttons = machine.buttons.len();
 
     let mut vars_problem = ProblemVariables::new();
@@ -45,7 +20,7 @@ fn min_presses_ilp(machine: &Machine) -> u32 {
     let mut model = vars_problem.minimise(objective).using(microlp);
 
     for counter_idx in 0..num_counters {
-        let target_val = machine.target[counter_idx] as f64;
+        let target_val = machine.joltages[counter_idx] as f64;
 
         let mut constraint_expr = Expression::from(0.0);
         for (button_idx, button) in machine.buttons.iter().enumerate() {
@@ -57,23 +32,27 @@ fn min_presses_ilp(machine: &Machine) -> u32 {
         model = model.with(constraint!(constraint_expr == target_val));
     }
 
-    let solution = model.solve();
+    let sol = model.solve().ok()?;
+    Some(vars.iter().map(|&var| sol.value(var).round() as u64).sum())
+}
 
-    match solution {
-        Ok(sol) => {
-            let mut total = 0;
-            for var in vars.iter().take(num_buttons) {
-                total += sol.value(*var).round() as u32;
-            }
-            total
-        }
-        Err(e) => {
-            panic!("No solution found for machine: {:?}", e);
-        }
-    }
+pub fn solve(input: &str) -> Result<u32, MachineError> {
+    total(&parse_machines(input)?)
 }
 
-pub fn solve(lines: &[String]) -> u32 {
-    let machines = lines.iter().map(|l| parse_machine(l)).collect::<Vec<_>>();
-    machines.iter().map(min_presses_ilp).sum()
+/// Fewest presses to bring every machine's counters to their joltages,
+/// added up.
+pub fn total(machines: &[Machine]) -> Result<u32, MachineError> {
+    let mut total: u32 = 0;
+    for machine in machines {
+        let presses =
+            min_presses_ilp(machine).ok_or(MachineError::Unreachable { line: machine.line })?;
+        total = u32::try_from(presses)
+            .ok()
+            .and_then(|p| total.checked_add(p))
+            .ok_or_else(|| {
+                OverflowError::new::<u32>(format!("the total presses up to line {}", machine.line))
+            })?;
+    }
+    Ok(total)
 }
@@ -1,2 +1,82 @@
+use std::error::Error;
+use std::fmt;
+
+use anyhow::Result;
+use aoc_common::{OverflowError, ParseError, Solution};
+
+mod generator;
 pub mod indicator_presses;
 pub mod joltage_presses;
+mod machine;
+
+pub use machine::{Machine, parse_machine, parse_machines};
+
+/// Why the fewest presses for a list of machines could not be counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MachineError {
+    Parse(ParseError),
+    Overflow(OverflowError),
+    /// No combination of presses brings the machine on `line` to its target.
+    Unreachable {
+        line: usize,
+    },
+    /// The machine on `line` has more buttons than part one searches.
+    TooManyButtons {
+        line: usize,
+        buttons: usize,
+    },
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineError::Parse(e) => e.fmt(f),
+            MachineError::Overflow(e) => e.fmt(f),
+            MachineError::Unreachable { line } => {
+                write!(f, "the machine on line {} cannot reach its target", line)
+            }
+            MachineError::TooManyButtons { line, buttons } => write!(
+                f,
+                "the machine on line {} has {} buttons, more than the {} part one searches",
+                line,
+                buttons,
+                indicator_presses::MAX_BUTTONS
+            ),
+        }
+    }
+}
+
+impl Error for MachineError {}
+
+impl From<ParseError> for MachineError {
+    fn from(e: ParseError) -> Self {
+        MachineError::Parse(e)
+    }
+}
+
+impl From<OverflowError> for MachineError {
+    fn from(e: OverflowError) -> Self {
+        MachineError::Overflow(e)
+    }
+}
+
+pub struct DayTen;
+
+impl Solution for DayTen {
+    const DAY: u8 = 10;
+    type Input = Vec<Machine>;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_machines(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        Ok(indicator_presses::total(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<u32> {
+        Ok(joltage_presses::total(input)?)
+    }
+}
@@ -0,0 +1,117 @@
+use aoc_common::{ParseError, non_blank_lines, split_integers};
+
+/// One machine: the light pattern part one must show, the lights or
+/// counters each button is wired to, and the joltage each counter must reach
+/// in part two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    /// Input line the machine was read from, for error messages.
+    pub line: usize,
+    pub lights: Vec<bool>,
+    pub buttons: Vec<Vec<usize>>,
+    pub joltages: Vec<u32>,
+}
+
+/// Every machine in `input`, one per non-blank line.
+pub fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    non_blank_lines(input)
+        .map(|(line_no, line)| parse_machine(line_no, line))
+        .collect()
+}
+
+/// Parse `[.##.] (3) (1,3) ... {3,5,4,7}`: the lights, each button's wiring
+/// and one joltage target per light.
+pub fn parse_machine(line_no: usize, line: &str) -> Result<Machine, ParseError> {
+    let light_part = line
+        .trim_start()
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(lights, _)| lights)
+        .ok_or_else(|| {
+            ParseError::in_line(line_no, line, line, "indicator lights like '[.##.]'")
+        })?;
+
+    let lights = light_part
+        .char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::in_line(
+                line_no,
+                line,
+                &light_part[i..i + c.len_utf8()],
+                "'.' or '#'",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let start = line.rfind('{').ok_or_else(|| {
+        ParseError::in_line(line_no, line, line, "'{' before the joltage targets")
+    })?;
+    let end = line[start..].find('}').map(|i| start + i).ok_or_else(|| {
+        ParseError::in_line(
+            line_no,
+            line,
+            &line[start..],
+            "'}' after the joltage targets",
+        )
+    })?;
+    let joltage_text = &line[start + 1..end];
+
+    let joltages = split_integers::<u32>(line_no, line, joltage_text, ',', "a joltage target")
+        .collect::<Result<Vec<_>, _>>()?;
+    if joltages.len() != lights.len() {
+        return Err(ParseError::in_line(
+            line_no,
+            line,
+            joltage_text,
+            format!("{} joltage targets, one per light", lights.len()),
+        ));
+    }
+
+    let buttons = parse_buttons(line_no, line, &line[..start], lights.len())?;
+
+    Ok(Machine {
+        line: line_no,
+        lights,
+        buttons,
+        joltages,
+    })
+}
+
+/// Parse every `(i,j,...)` button wiring in `text`, a slice of `line`. Each
+/// index must address one of the machine's `slots` lights or counters.
+fn parse_buttons(
+    line_no: usize,
+    line: &str,
+    text: &str,
+    slots: usize,
+) -> Result<Vec<Vec<usize>>, ParseError> {
+    let mut buttons = vec![];
+    for chunk in text.split('(').skip(1) {
+        let (inside, _) = chunk
+            .split_once(')')
+            .ok_or_else(|| ParseError::in_line(line_no, line, chunk, "')' to close a button"))?;
+        let indices = inside
+            .split(',')
+            .zip(split_integers::<usize>(
+                line_no,
+                line,
+                inside,
+                ',',
+                "a button index",
+            ))
+            .map(|(token, idx)| match idx? {
+                idx if idx < slots => Ok(idx),
+                _ => Err(ParseError::in_line(
+                    line_no,
+                    line,
+                    token.trim(),
+                    format!("an index below {}", slots),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        buttons.push(indices);
+    }
+    Ok(buttons)
+}
@@ -1,12 +1,13 @@
+use aoc_common::{OverflowError, ParseError};
+use day_ten::MachineError;
 use day_ten::indicator_presses;
 use day_ten::joltage_presses;
 
 #[test]
 fn example_indicator_lights() {
     let s = include_str!("../example.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let result = indicator_presses::solve(&lines);
+    let result = indicator_presses::solve(s).unwrap();
     // For the 3 example machines:
     // Machine 1: [.##.] with 6 buttons -> optimal solution
     // Machine 2: [...#.] with 5 buttons -> optimal solution
@@ -21,9 +22,8 @@ fn example_indicator_lights() {
 #[test]
 fn example_joltage_counters() {
     let s = include_str!("../example.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let result = joltage_presses::solve(&lines);
+    let result = joltage_presses::solve(s).unwrap();
     // For the 3 example machines with joltage targets:
     // Machine 1: targets {3,5,4,7} -> ILP finds minimum presses
     // Machine 2: targets {7,5,12,7,2} -> ILP finds minimum presses
@@ -38,9 +38,8 @@ fn example_joltage_counters() {
 #[test]
 fn input_indicator_lights() {
     let s = include_str!("../input.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let result = indicator_presses::solve(&lines);
+    let result = indicator_presses::solve(s).unwrap();
     assert_eq!(
         result, 571,
         "Expected 571 total presses for indicator lights"
@@ -50,11 +49,90 @@ fn input_indicator_lights() {
 #[test]
 fn input_joltage_counters() {
     let s = include_str!("../input.txt");
-    let lines: Vec<String> = s.lines().map(|l| l.to_string()).collect();
 
-    let result = joltage_presses::solve(&lines);
+    let result = joltage_presses::solve(s).unwrap();
     assert_eq!(
         result, 20869,
         "Expected 20869 total presses for joltage counters"
     );
 }
+
+#[test]
+fn malformed_machines_are_errors() {
+    let err = indicator_presses::solve("[.#] (0) (2) {1,2}").unwrap_err();
+    assert_eq!(
+        err,
+        MachineError::Parse(ParseError::new(1, 11, "2", "an index below 2"))
+    );
+
+    let Err(MachineError::Parse(err)) = indicator_presses::solve("[.x] (0) {1,2}") else {
+        panic!("expected a parse error");
+    };
+    assert_eq!((err.column, err.text.as_str()), (3, "x"));
+
+    let err = joltage_presses::solve("[.#] (0,1) {1,two}").unwrap_err();
+    assert_eq!(
+        err,
+        MachineError::Parse(ParseError::new(1, 15, "two", "a joltage target"))
+    );
+
+    assert!(joltage_presses::solve("[.#] (0,1)").is_err());
+
+    let err = day_ten::parse_machine(4, "[.#] (0,1) {1,2,3}").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::new(4, 13, "1,2,3", "2 joltage targets, one per light")
+    );
+}
+
+#[test]
+fn input_is_parsed_once() {
+    use aoc_common::Solution;
+    use day_ten::DayTen;
+
+    // A bad index fails `parse`, before either part runs.
+    assert!(DayTen::parse("[.#] (0) (2) {1,2}").is_err());
+
+    let machines = DayTen::parse(include_str!("../example.txt")).unwrap();
+    assert_eq!(machines.len(), 3);
+    assert_eq!(machines[0].lights, [false, true, true, false]);
+    assert_eq!(machines[0].buttons[1], [1, 3]);
+    assert_eq!(machines[0].joltages, [3, 5, 4, 7]);
+    assert_eq!(DayTen::part_one(&machines).unwrap(), 7);
+    assert_eq!(DayTen::part_two(&machines).unwrap(), 33);
+}
+
+#[test]
+fn unreachable_targets_are_errors() {
+    let err = indicator_presses::solve("[##] (0,1) {1,1}\n[.#] (0) {1,1}").unwrap_err();
+    assert_eq!(err, MachineError::Unreachable { line: 2 });
+    assert_eq!(
+        err.to_string(),
+        "the machine on line 2 cannot reach its target"
+    );
+
+    let err = joltage_presses::solve("[##] (0,1) {1,1}\n[##] (0,1) {1,2}").unwrap_err();
+    assert_eq!(err, MachineError::Unreachable { line: 2 });
+}
+
+#[test]
+fn oversized_machines_are_errors() {
+    let buttons = " (0)".repeat(indicator_presses::MAX_BUTTONS + 1);
+    let input = format!("[#]{} {{1}}", buttons);
+    let err = indicator_presses::solve(&input).unwrap_err();
+    assert_eq!(
+        err,
+        MachineError::TooManyButtons {
+            line: 1,
+            buttons: 17
+        }
+    );
+    // Only part one searches every subset of buttons.
+    assert_eq!(joltage_presses::solve(&input), Ok(1));
+
+    let err = joltage_presses::solve(&"[#] (0) {2147483647}\n".repeat(3)).unwrap_err();
+    assert_eq!(
+        err,
+        MachineError::Overflow(OverflowError::new::<u32>("the total presses up to line 3"))
+    );
+}
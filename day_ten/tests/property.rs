@@ -0,0 +1,141 @@
+use std::collections::{HashMap, VecDeque};
+
+use proptest::prelude::*;
+
+/// A machine: counter count and button wiring, plus how often each button is
+/// pressed to set its targets and whether it is pressed an odd number of times
+/// to set its lights.
+#[derive(Debug, Clone)]
+struct Machine {
+    size: usize,
+    buttons: Vec<Vec<usize>>,
+    presses: Vec<u32>,
+    toggled: Vec<bool>,
+}
+
+impl Machine {
+    fn lights(&self) -> Vec<bool> {
+        let mut lights = vec![false; self.size];
+        for (wiring, _) in self.buttons.iter().zip(&self.toggled).filter(|(_, t)| **t) {
+            wiring.iter().for_each(|&i| lights[i] = !lights[i]);
+        }
+        lights
+    }
+
+    fn targets(&self) -> Vec<u32> {
+        let mut targets = vec![0; self.size];
+        for (wiring, &n) in self.buttons.iter().zip(&self.presses) {
+            wiring.iter().for_each(|&i| targets[i] += n);
+        }
+        targets
+    }
+
+    fn render(&self) -> String {
+        let lights: String = self
+            .lights()
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        let buttons: Vec<String> = self
+            .buttons
+            .iter()
+            .map(|w| {
+                format!(
+                    "({})",
+                    w.iter().map(usize::to_string).collect::<Vec<_>>().join(",")
+                )
+            })
+            .collect();
+        let targets: Vec<String> = self.targets().iter().map(u32::to_string).collect();
+        format!(
+            "[{}] {} {{{}}}\n",
+            lights,
+            buttons.join(" "),
+            targets.join(",")
+        )
+    }
+}
+
+/// Fewest presses to reach the light pattern, by breadth-first search over states.
+fn fewest_toggles(machine: &Machine) -> usize {
+    let goal = machine.lights();
+    let mut dist = HashMap::from([(vec![false; machine.size], 0)]);
+    let mut queue = VecDeque::from([vec![false; machine.size]]);
+    while let Some(state) = queue.pop_front() {
+        let d = dist[&state];
+        if state == goal {
+            return d;
+        }
+        for wiring in &machine.buttons {
+            let mut next = state.clone();
+            wiring.iter().for_each(|&i| next[i] = !next[i]);
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    unreachable!("the pattern was made by pressing buttons")
+}
+
+/// Fewest presses to reach the targets, trying every press count up to the
+/// lowest target each button feeds.
+fn fewest_presses(machine: &Machine) -> u32 {
+    let targets = machine.targets();
+    let limits: Vec<u32> = machine
+        .buttons
+        .iter()
+        .map(|w| w.iter().map(|&i| targets[i]).min().unwrap())
+        .collect();
+    let mut counts = vec![0u32; limits.len()];
+    let mut best = u32::MAX;
+    loop {
+        let mut reached = vec![0; machine.size];
+        for (wiring, &n) in machine.buttons.iter().zip(&counts) {
+            wiring.iter().for_each(|&i| reached[i] += n);
+        }
+        if reached == targets {
+            best = best.min(counts.iter().sum());
+        }
+        // Advance the counts like an odometer.
+        let Some(i) = (0..counts.len()).find(|&i| counts[i] < limits[i]) else {
+            return best;
+        };
+        counts[..i].fill(0);
+        counts[i] += 1;
+    }
+}
+
+fn machine() -> impl Strategy<Value = Machine> {
+    (1usize..=4, 1usize..=4).prop_flat_map(|(size, buttons)| {
+        (
+            prop::collection::vec(prop::collection::btree_set(0..size, 1..=size), buttons),
+            prop::collection::vec(0u32..4, buttons),
+            prop::collection::vec(any::<bool>(), buttons),
+        )
+            .prop_map(move |(wiring, presses, toggled)| Machine {
+                size,
+                buttons: wiring
+                    .into_iter()
+                    .map(|w| w.into_iter().collect())
+                    .collect(),
+                presses,
+                toggled,
+            })
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_exhaustive_search(machines in prop::collection::vec(machine(), 1..4)) {
+        let input: String = machines.iter().map(Machine::render).collect();
+        prop_assert_eq!(
+            day_ten::indicator_presses::solve(&input).unwrap(),
+            machines.iter().map(fewest_toggles).sum::<usize>()
+        );
+        prop_assert_eq!(
+            day_ten::joltage_presses::solve(&input).unwrap(),
+            machines.iter().map(fewest_presses).sum::<u32>()
+        );
+    }
+}
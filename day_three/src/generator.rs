@@ -0,0 +1,23 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayThree;
+
+/// Batteries per bank, as in the puzzle input.
+const BANK_LEN: usize = 100;
+
+impl Generator for DayThree {
+    const SIZES: &'static [usize] = &[250, 1_000, 4_000, 16_000];
+
+    /// `size` banks of 100 battery joltages from 1 to 9.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::with_capacity(size * (BANK_LEN + 1));
+        for _ in 0..size {
+            for _ in 0..BANK_LEN {
+                input.push(char::from(b'0' + rng.range(1..=9) as u8));
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
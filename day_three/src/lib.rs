@@ -1,40 +1,152 @@
-use std::num::ParseIntError;
+use std::error::Error;
+use std::fmt;
 
-pub fn largest_two_digit(s: &str) -> u32 {
-    let digit_count = s.chars().filter(|c| c.is_ascii_digit()).count();
-    if digit_count < 2 {
+use anyhow::Result;
+use aoc_common::{OverflowError, ParseError, Solution, non_blank_lines};
+
+mod generator;
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<u8>>;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_banks(input)?)
+    }
+
+    fn part_one(banks: &Self::Input) -> Result<u32> {
+        Ok(banks.iter().map(|bank| largest_pair(bank)).sum())
+    }
+
+    fn part_two(banks: &Self::Input) -> Result<u64> {
+        Ok(banks
+            .iter()
+            .map(|bank| largest_from_digits(bank, 12).unwrap_or(0))
+            .sum())
+    }
+}
+
+/// Why no number could be formed from a bank's digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DigitsError {
+    Parse(ParseError),
+    /// Asked to keep no digits at all.
+    KeepNone,
+    /// The bank has no digits to keep.
+    NoDigits,
+    Overflow(OverflowError),
+}
+
+impl fmt::Display for DigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DigitsError::Parse(e) => e.fmt(f),
+            DigitsError::KeepNone => write!(f, "asked to keep no digits"),
+            DigitsError::NoDigits => write!(f, "no digits to keep"),
+            DigitsError::Overflow(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for DigitsError {}
+
+impl From<ParseError> for DigitsError {
+    fn from(e: ParseError) -> Self {
+        DigitsError::Parse(e)
+    }
+}
+
+impl From<OverflowError> for DigitsError {
+    fn from(e: OverflowError) -> Self {
+        DigitsError::Overflow(e)
+    }
+}
+
+/// The digits of each non-blank line of `input` as values 0 to 9, one bank
+/// per line.
+pub fn parse_banks(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    non_blank_lines(input)
+        .map(|(line_no, line)| parse_bank(line_no, line))
+        .collect()
+}
+
+/// The digits of one bank, line `line_no` of the input. Anything but a digit
+/// is an error, so a stray character cannot quietly change the answer.
+pub fn parse_bank(line_no: usize, line: &str) -> Result<Vec<u8>, ParseError> {
+    let bank = line.trim();
+    bank.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(d) => Ok(d as u8),
+            None => Err(ParseError::in_line(
+                line_no,
+                line,
+                &bank[i..i + c.len_utf8()],
+                "a joltage digit",
+            )),
+        })
+        .collect()
+}
+
+pub fn largest_two_digit(s: &str) -> Result<u32, ParseError> {
+    Ok(largest_pair(&parse_bank(1, s)?))
+}
+
+/// Largest two-digit number from `bank`, or 0 if it has fewer than two digits.
+fn largest_pair(bank: &[u8]) -> u32 {
+    if bank.len() < 2 {
         return 0;
     }
-    largest_n_digit(s, 2).unwrap_or(0) as u32
+    largest_from_digits(bank, 2).map_or(0, |v| v as u32)
 }
 
-pub fn largest_twelve_digit(s: &str) -> u64 {
-    largest_n_digit(s, 12).unwrap_or(0)
+pub fn largest_twelve_digit(s: &str) -> Result<u64, ParseError> {
+    Ok(largest_from_digits(&parse_bank(1, s)?, 12).unwrap_or(0))
 }
 
-pub fn largest_n_digit(s: &str, keep: usize) -> Result<u64, ParseIntError> {
-    let digits: Vec<char> = s.chars().filter(|c| c.is_ascii_digit()).collect();
+/// Largest number formed by keeping `keep` of the digits of the bank `s`, in
+/// order.
+pub fn largest_n_digit(s: &str, keep: usize) -> Result<u64, DigitsError> {
+    largest_from_digits(&parse_bank(1, s)?, keep)
+}
+
+/// Largest number formed by keeping `keep` of `digits`, in order, or all of
+/// them if there are no more than `keep`. The kept digits are accumulated
+/// directly into the result, which doubles as the stack of the greedy
+/// selection.
+pub fn largest_from_digits(digits: &[u8], keep: usize) -> Result<u64, DigitsError> {
     if keep == 0 {
-        return Err("empty".parse::<u64>().unwrap_err());
+        return Err(DigitsError::KeepNone);
     }
-    let n = digits.len();
-    if n <= keep {
-        let collected: String = digits.into_iter().collect();
-        return collected.parse::<u64>();
+    if digits.is_empty() {
+        return Err(DigitsError::NoDigits);
     }
 
-    let mut remove = n - keep;
-    let mut stack: Vec<char> = Vec::with_capacity(keep);
+    let mut remove = digits.len().saturating_sub(keep);
+    let mut value = 0u64;
+    let mut len = 0usize;
 
-    for d in digits {
-        while remove > 0 && stack.last().is_some_and(|&c| c < d) {
-            stack.pop();
+    for d in digits.iter().map(|&d| u64::from(d)) {
+        while remove > 0 && len > 0 && value % 10 < d {
+            value /= 10;
+            len -= 1;
+            remove -= 1;
+        }
+        if len < keep {
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(d))
+                .ok_or_else(|| {
+                    OverflowError::new::<u64>(format!("the largest {}-digit number", keep))
+                })?;
+            len += 1;
+        } else {
             remove -= 1;
         }
-        stack.push(d);
     }
 
-    stack.truncate(keep);
-    let s: String = stack.into_iter().collect();
-    s.parse::<u64>()
+    Ok(value)
 }
@@ -1,10 +1,11 @@
-use day_three::{largest_n_digit, largest_two_digit};
+use aoc_common::{ParseError, Solution};
+use day_three::{DayThree, DigitsError, largest_n_digit, largest_two_digit};
 
 #[test]
 fn test_largest_two_digit_basic() {
-    assert_eq!(largest_two_digit("3052"), 52);
-    assert_eq!(largest_two_digit("ab1c2"), 12);
-    assert_eq!(largest_two_digit("9"), 0);
+    assert_eq!(largest_two_digit("3052"), Ok(52));
+    assert_eq!(largest_two_digit(" 12 "), Ok(12));
+    assert_eq!(largest_two_digit("9"), Ok(0));
 }
 
 #[test]
@@ -12,9 +13,53 @@ fn test_largest_n_digit_basic() {
     assert_eq!(largest_n_digit("123456", 3).unwrap(), 456);
     assert_eq!(largest_n_digit("7654321", 4).unwrap(), 7654);
     assert_eq!(largest_n_digit("42", 5).unwrap(), 42);
+    assert_eq!(largest_n_digit("54321", 2).unwrap(), 54);
+    assert_eq!(
+        largest_n_digit("818181911112111", 12).unwrap(),
+        888911112111
+    );
 }
 
 #[test]
 fn test_largest_n_digit_errors() {
-    assert!(largest_n_digit("1234", 0).is_err());
+    assert_eq!(largest_n_digit("1234", 0), Err(DigitsError::KeepNone));
+    assert_eq!(largest_n_digit("", 2), Err(DigitsError::NoDigits));
+    assert_eq!(
+        largest_n_digit("1b2", 2),
+        Err(DigitsError::Parse(ParseError::new(
+            1,
+            2,
+            "b",
+            "a joltage digit"
+        )))
+    );
+    let err = largest_n_digit("99999999999999999999", 20).unwrap_err();
+    assert!(matches!(err, DigitsError::Overflow(_)));
+    assert_eq!(
+        err.to_string(),
+        "the largest 20-digit number does not fit in u64"
+    );
+    assert_eq!(
+        largest_n_digit("0000000000000000000000042", 30).unwrap(),
+        42
+    );
+}
+
+#[test]
+fn parses_banks_up_front() {
+    let banks = DayThree::parse("3052\n12\n\n9\n").unwrap();
+    assert_eq!(banks, vec![vec![3, 0, 5, 2], vec![1, 2], vec![9]]);
+    assert_eq!(DayThree::part_one(&banks).unwrap(), 52 + 12);
+    assert_eq!(DayThree::part_two(&banks).unwrap(), 3052 + 12 + 9);
+}
+
+#[test]
+fn stray_characters_are_errors() {
+    let err = day_three::parse_banks("3052\n1x2\n").unwrap_err();
+    assert_eq!(err, ParseError::new(2, 2, "x", "a joltage digit"));
+    assert_eq!(
+        largest_two_digit("ab1c2"),
+        Err(ParseError::new(1, 1, "a", "a joltage digit"))
+    );
+    assert!(DayThree::parse("987654321111111\n81119-\n").is_err());
 }
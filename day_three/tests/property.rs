@@ -0,0 +1,33 @@
+use proptest::prelude::*;
+
+/// Largest number from any `keep` digits of `digits` in order, trying every
+/// subsequence. Keeps all digits when there are no more than `keep`.
+fn exhaustive(digits: &[u8], keep: usize) -> u64 {
+    let n = digits.len();
+    if keep >= n {
+        return digits.iter().fold(0, |v, &d| v * 10 + u64::from(d));
+    }
+    (0u32..1 << n)
+        .filter(|mask| mask.count_ones() as usize == keep)
+        .map(|mask| {
+            (0..n)
+                .filter(|i| mask & (1 << i) != 0)
+                .fold(0, |v, i| v * 10 + u64::from(digits[i]))
+        })
+        .max()
+        .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn greedy_matches_exhaustive_search(
+        digits in prop::collection::vec(0u8..10, 1..=14),
+        keep in 1usize..=16,
+    ) {
+        let s: String = digits.iter().map(|d| char::from(b'0' + d)).collect();
+        prop_assert_eq!(
+            day_three::largest_n_digit(&s, keep).unwrap(),
+            exhaustive(&digits, keep)
+        );
+    }
+}
@@ -1,136 +1,102 @@
 use std::collections::HashMap;
+use aoc_common::{ParseError, Section, parse_integer, split_sections_by_header};
 use rayon::prelude::*;
 
-pub fn run(lines: &[String]) -> usize {
-    let (shapes_raw, regions) = parse_input(lines);
-    
-    let mut shape_areas: Vec<usize> = vec![];
-    let max_shape_idx = shapes_raw.keys().max().copied().unwrap_or(0);
-    for idx in 0..=max_shape_idx {
-        let s = shapes_raw.get(&idx).cloned().unwrap_or_else(Vec::new);
-        let area = count_hashes(&s);
-        shape_areas.push(area);
-    }
-
-   
-    regions.par_iter()
-        .enumerate()
-        .filter(|(_, (w, h, counts))| {
-            can_fit_by_area(*w, *h, &shape_areas, counts)
-        })
-        .count()
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    let (shapes, regions) = parse_input(input)?;
+    Ok(count_fitting(&shape_areas(&shapes), &regions))
 }
 
-type ShapeGrid = Vec<String>;
-type Region = (usize, usize, Vec<usize>);
-type ParseResult = (HashMap<usize, ShapeGrid>, Vec<Region>);
+/// Shape indices must stay below this; areas are stored in a `Vec` indexed
+/// by shape number.
+pub const MAX_SHAPES: usize = 1 << 16;
 
-pub fn parse_input(lines: &[String]) -> ParseResult {
-    let mut shapes: HashMap<usize, ShapeGrid> = HashMap::new();
-    let mut regions: Vec<(usize, usize, Vec<usize>)> = Vec::new();
-
-    #[derive(PartialEq)]
-    enum State { Shapes, Regions }
-    let mut state = State::Shapes;
+pub type ShapeGrid<'a> = Vec<&'a str>;
+pub type Region = (usize, usize, Vec<usize>);
+pub type ParseResult<'a> = (HashMap<usize, ShapeGrid<'a>>, Vec<Region>);
 
-    let mut i = 0usize;
-    while i < lines.len() {
-        let line = lines[i].trim();
-        if line.is_empty() {
-            i += 1;
-            continue;
-        }
-        
-        if state == State::Shapes
-            && let Some(colpos) = line.find(':')
-        {
-            let left = &line[..colpos];
-            if left.contains('x') && left.chars().all(|c| c.is_ascii_digit() || c=='x') {
-                state = State::Regions;
-                continue;
-            }
-        }
+/// Shape blocks open with an index header such as `4:`; anything else
+/// (normally the trailing block of `WxH: counts` lines) is read as regions.
+pub fn parse_input(input: &str) -> Result<ParseResult<'_>, ParseError> {
+    let mut shapes: HashMap<usize, ShapeGrid> = HashMap::new();
+    let mut regions: Vec<Region> = Vec::new();
 
-        match state {
-            State::Shapes => {
-                if let Some(colpos) = line.find(':') {
-                    let idx_str = line[..colpos].trim();
-                    if let Ok(idx) = idx_str.parse::<usize>() {
-                        let after = line[colpos+1..].trim();
-                        let mut grid: Vec<String> = Vec::new();
-                        if !after.is_empty() {
-                            for token in after.split_whitespace() {
-                                if token.chars().all(|c| c=='.' || c=='#') {
-                                    grid.push(token.to_string());
-                                }
-                            }
-                            shapes.insert(idx, grid);
-                            i += 1;
-                            continue;
-                        } else {
-                            i += 1;
-                            while i < lines.len() {
-                                let l = lines[i].trim();
-                                if l.is_empty() { break; }
-                                if l.ends_with(':') && l[..l.len()-1].chars().all(|c| c.is_ascii_digit()) {
-                                    break;
-                                }
-                                if let Some(colpos2) = l.find(':') {
-                                    let left2 = &l[..colpos2];
-                                    if left2.contains('x') && left2.chars().all(|c| c.is_ascii_digit() || c=='x') {
-                                        break;
-                                    }
-                                }
-                                grid.push(l.to_string());
-                                i += 1;
-                            }
-                            shapes.insert(idx, grid);
-                            continue;
-                        }
-                    }
-                }
-                i += 1;
+    for section in split_sections_by_header(input, is_shape_header) {
+        match section.name {
+            Some(name) => {
+                let idx = name
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&idx| idx < MAX_SHAPES)
+                    .ok_or_else(|| {
+                        ParseError::in_input(
+                            input,
+                            name,
+                            format!("a shape index below {}", MAX_SHAPES),
+                        )
+                    })?;
+                shapes.insert(idx, parse_shape(input, &section)?);
             }
-            State::Regions => {
-                let rest = line;
-                if !rest.is_empty()
-                    && let Some(colpos) = rest.find(':')
-                {
-                    let left = rest[..colpos].trim();
-                    let after_part = rest[colpos+1..].trim();
-                    if let Some(xpos) = left.find('x') {
-                        let w_s = &left[..xpos];
-                        let h_s = &left[xpos+1..];
-                        if let (Ok(w), Ok(h)) = (w_s.parse::<usize>(), h_s.parse::<usize>()) {
-                            let mut nums: Vec<usize> = Vec::new();
-                            let mut tokens: Vec<String> = after_part.split_whitespace().map(|s| s.to_string()).collect();
-                            let mut look = 1usize;
-                            while tokens.len() < 6 && i + look < lines.len() {
-                                let more = lines[i + look].trim();
-                                if more.is_empty() { break; }
-                                for t in more.split_whitespace() { tokens.push(t.to_string()); }
-                                look += 1;
-                            }
-                            for tok in tokens.iter().take(6) {
-                                if let Ok(n) = tok.parse::<usize>() {
-                                    nums.push(n);
-                                }
-                            }
-                            if nums.len() == 6 {
-                                regions.push((w, h, nums));
-                            }
-                            if look > 1 {
-                                i += look - 1;
-                            }
-                        }
-                    }
+            None => {
+                for (line_no, line) in section.numbered_lines() {
+                    regions.push(parse_region(line_no, line)?);
                 }
-                i += 1;
             }
         }
     }
 
-    (shapes, regions)
+    Ok((shapes, regions))
+}
+
+fn is_shape_header(line: &str) -> bool {
+    line.trim()
+        .strip_suffix(':')
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn parse_shape<'a>(input: &str, section: &Section<'a>) -> Result<ShapeGrid<'a>, ParseError> {
+    section
+        .lines()
+        .map(|line| {
+            let row = line.trim();
+            match row.find(|c| c != '.' && c != '#') {
+                Some(pos) => Err(ParseError::in_input(input, &row[pos..], "'.' or '#'")),
+                None => Ok(row),
+            }
+        })
+        .collect()
+}
+
+fn parse_region(line_no: usize, line: &str) -> Result<Region, ParseError> {
+    let text = line.trim();
+    let (size, counts) = text
+        .split_once(':')
+        .ok_or_else(|| ParseError::in_line(line_no, line, text, "a region 'WxH: counts'"))?;
+    let (w, h) = size
+        .split_once('x')
+        .ok_or_else(|| ParseError::in_line(line_no, line, size, "a region size 'WxH'"))?;
+    let w = parse_integer(line_no, line, w.trim(), "a region width")?;
+    let h = parse_integer(line_no, line, h.trim(), "a region height")?;
+    let counts = counts
+        .split_whitespace()
+        .map(|t| parse_integer(line_no, line, t, "a shape count"))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((w, h, counts))
+}
+
+/// Areas indexed by shape number; missing indices count as empty shapes.
+pub fn shape_areas(shapes: &HashMap<usize, ShapeGrid>) -> Vec<usize> {
+    let max_shape_idx = shapes.keys().max().copied().unwrap_or(0);
+    (0..=max_shape_idx)
+        .map(|idx| shapes.get(&idx).map_or(0, count_hashes))
+        .collect()
+}
+
+pub fn count_fitting(shape_areas: &[usize], regions: &[Region]) -> usize {
+    regions
+        .par_iter()
+        .filter(|(w, h, counts)| can_fit_by_area(*w, *h, shape_areas, counts))
+        .count()
 }
 
 pub fn count_hashes(grid: &ShapeGrid) -> usize {
@@ -139,15 +105,18 @@ pub fn count_hashes(grid: &ShapeGrid) -> usize {
         .sum()
 }
 
+/// Areas are compared as `u128`. The region area always fits; the required
+/// area saturates, which still compares correctly since no region reaches
+/// `u128::MAX`.
 pub fn can_fit_by_area(w: usize, h: usize, shape_areas: &[usize], counts: &[usize]) -> bool {
-    let region_area = w * h;
-    
-    let mut required_area = 0usize;
+    let region_area = w as u128 * h as u128;
+
+    let mut required_area = 0u128;
     for (i, &count) in counts.iter().enumerate() {
         if i < shape_areas.len() {
-            required_area += count * shape_areas[i];
+            required_area = required_area.saturating_add(count as u128 * shape_areas[i] as u128);
         }
     }
-    
+
     required_area <= region_area
 }
@@ -1,5 +1,7 @@
-pub fn solve(lines: &[String]) -> usize {
-    inner::run(lines)
+use aoc_common::ParseError;
+
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    inner::run(input)
 }
 
 mod inner {
@@ -8,4 +10,6 @@ mod inner {
 
 // Re-export the public functions from the inner module for testing
 #[allow(unused_imports)]
-pub use inner::{can_fit_by_area, count_hashes, parse_input};
+pub use inner::{
+    Region, ShapeGrid, can_fit_by_area, count_fitting, count_hashes, parse_input, shape_areas,
+};
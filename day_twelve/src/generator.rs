@@ -0,0 +1,51 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayTwelve;
+
+/// Present shapes, each on a 3x3 grid, as in the puzzle input.
+const SHAPES: usize = 6;
+
+impl Generator for DayTwelve {
+    const SIZES: &'static [usize] = &[1_000, 4_000, 16_000, 64_000];
+
+    /// Six random shapes followed by `size` regions between 35 and 50 units on
+    /// a side. Present counts aim at 70% to 110% of each region's area, so
+    /// some regions fit their presents and some do not.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+
+        let mut areas = Vec::with_capacity(SHAPES);
+        for idx in 0..SHAPES {
+            let mut cells = [false; 9];
+            let filled = rng.range(5..=7) as usize;
+            cells[..filled].fill(true);
+            rng.shuffle(&mut cells);
+            areas.push(filled as u64);
+
+            input.push_str(&format!("{}:\n", idx));
+            for row in cells.chunks(3) {
+                input.extend(row.iter().map(|&c| if c { '#' } else { '.' }));
+                input.push('\n');
+            }
+            input.push('\n');
+        }
+
+        for _ in 0..size {
+            let (w, h) = (rng.range(35..=50), rng.range(35..=50));
+            let mut budget = w * h * rng.range(70..=110) / 100;
+            let mut counts = [0u64; SHAPES];
+            loop {
+                let shape = rng.index(SHAPES);
+                let Some(rest) = budget.checked_sub(areas[shape]) else {
+                    break;
+                };
+                budget = rest;
+                counts[shape] += 1;
+            }
+            let counts: Vec<String> = counts.iter().map(u64::to_string).collect();
+            input.push_str(&format!("{}x{}: {}\n", w, h, counts.join(" ")));
+        }
+        input
+    }
+}
@@ -1 +1,29 @@
+use anyhow::{Result, bail};
+use aoc_common::Solution;
+
 pub mod christmas_tree_farm;
+mod generator;
+
+pub struct DayTwelve;
+
+impl Solution for DayTwelve {
+    const DAY: u8 = 12;
+    const HAS_PART_TWO: bool = false;
+    /// Shape areas by index and the regions to fill.
+    type Input = (Vec<usize>, Vec<christmas_tree_farm::Region>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (shapes, regions) = christmas_tree_farm::parse_input(input)?;
+        Ok((christmas_tree_farm::shape_areas(&shapes), regions))
+    }
+
+    fn part_one((shape_areas, regions): &Self::Input) -> Result<usize> {
+        Ok(christmas_tree_farm::count_fitting(shape_areas, regions))
+    }
+
+    fn part_two(_: &Self::Input) -> Result<usize> {
+        bail!("day {} has no part two", Self::DAY)
+    }
+}
@@ -37,8 +37,7 @@ fn test_example() {
 12x5: 1 0 1 0 3 2
 "#;
 
-    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-    let (shapes, regions) = parse_input(&lines);
+    let (shapes, regions) = parse_input(input).unwrap();
 
     // Verify we parsed 6 shapes
     assert_eq!(shapes.len(), 6, "Should have 6 shapes");
@@ -144,8 +143,7 @@ fn test_shape_areas() {
 4x4: 0 0 0 0 2 0
 "#;
 
-    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-    let (shapes, _) = parse_input(&lines);
+    let (shapes, _) = parse_input(input).unwrap();
 
     let mut shape_areas = [0; 6];
     for (idx, grid) in shapes.iter() {
@@ -171,8 +169,7 @@ fn test_impossible_region() {
 2x2: 10 0 0 0 0 0
 "#;
 
-    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-    let (shapes, regions) = parse_input(&lines);
+    let (shapes, regions) = parse_input(input).unwrap();
 
     let mut shape_areas = [0; 1];
     for (idx, grid) in shapes.iter() {
@@ -197,8 +194,7 @@ fn test_exact_fit() {
 2x2: 2 0 0 0 0 0
 "#;
 
-    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-    let (shapes, regions) = parse_input(&lines);
+    let (shapes, regions) = parse_input(input).unwrap();
 
     let mut shape_areas = [0; 1];
     for (idx, grid) in shapes.iter() {
@@ -214,3 +210,28 @@ fn test_exact_fit() {
         "Should exactly fit 2 shapes of size 2 in a 2x2 region"
     );
 }
+
+#[test]
+fn malformed_regions_are_errors() {
+    let err = parse_input("0:\n##\n\n2x2: 1 x\n").unwrap_err();
+    assert_eq!((err.line, err.column), (4, 8));
+    assert_eq!(err.expected, "a shape count");
+
+    let err = parse_input("0:\n##\n\n2x99999999999999999999: 1\n").unwrap_err();
+    assert_eq!((err.line, err.column), (4, 3));
+    assert_eq!(err.expected, "a region height that fits in usize");
+
+    let err = parse_input("0:\n#?\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+
+    let err = parse_input("255552555:\n#\n").unwrap_err();
+    assert_eq!(err.expected, "a shape index below 65536");
+}
+
+#[test]
+fn huge_regions_do_not_overflow() {
+    let max = usize::MAX;
+    assert!(can_fit_by_area(max, max, &[3], &[max]));
+    assert!(!can_fit_by_area(2, 2, &[max, max], &[max, max]));
+    assert!(!can_fit_by_area(max, 2, &[2, 1], &[max, 1]));
+}
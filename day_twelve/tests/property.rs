@@ -0,0 +1,189 @@
+use std::collections::BTreeSet;
+
+use day_twelve::christmas_tree_farm as farm;
+use proptest::prelude::*;
+
+type Shape = [[bool; 3]; 3];
+type Region = (usize, usize, Vec<usize>);
+
+fn render(shapes: &[Shape], regions: &[Region]) -> String {
+    let mut input = String::new();
+    for (idx, shape) in shapes.iter().enumerate() {
+        input.push_str(&format!("{}:\n", idx));
+        for row in shape {
+            input.extend(row.iter().map(|&c| if c { '#' } else { '.' }));
+            input.push('\n');
+        }
+        input.push('\n');
+    }
+    for (w, h, counts) in regions {
+        let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+        input.push_str(&format!("{}x{}: {}\n", w, h, counts.join(" ")));
+    }
+    input
+}
+
+/// The filled cells of `shape` in each of its distinct rotations and
+/// reflections, shifted to start at row and column 0.
+fn orientations(shape: &Shape) -> Vec<Vec<(usize, usize)>> {
+    let cells: Vec<(i32, i32)> = (0..3)
+        .flat_map(|r| (0..3).map(move |c| (r, c)))
+        .filter(|&(r, c)| shape[r as usize][c as usize])
+        .collect();
+    let mut seen = BTreeSet::new();
+    for flip in [false, true] {
+        for turns in 0..4 {
+            let moved: Vec<(i32, i32)> = cells
+                .iter()
+                .map(|&(r, c)| {
+                    let (mut r, mut c) = if flip { (r, -c) } else { (r, c) };
+                    for _ in 0..turns {
+                        (r, c) = (c, -r);
+                    }
+                    (r, c)
+                })
+                .collect();
+            let r0 = moved.iter().map(|p| p.0).min().unwrap_or(0);
+            let c0 = moved.iter().map(|p| p.1).min().unwrap_or(0);
+            let normal: BTreeSet<(usize, usize)> = moved
+                .iter()
+                .map(|&(r, c)| ((r - r0) as usize, (c - c0) as usize))
+                .collect();
+            seen.insert(normal.into_iter().collect::<Vec<_>>());
+        }
+    }
+    seen.into_iter().collect()
+}
+
+/// Whether the presents, given by shape index, can all be placed in a
+/// `w`x`h` grid without overlapping, trying every orientation and position.
+fn packs(w: usize, h: usize, shapes: &[Shape], presents: &[usize]) -> bool {
+    fn place(
+        grid: &mut [bool],
+        placements: &[Vec<Vec<usize>>],
+        presents: &[usize],
+        // Identical presents take placements in order, to skip repeats.
+        from: usize,
+    ) -> bool {
+        let Some((&shape, rest)) = presents.split_first() else {
+            return true;
+        };
+        // Not enough free cells left: no search can succeed.
+        let needed: usize = presents
+            .iter()
+            .map(|&s| placements[s].first().map_or(0, Vec::len))
+            .sum();
+        if needed > grid.iter().filter(|&&used| !used).count() {
+            return false;
+        }
+        for (i, cells) in placements[shape].iter().enumerate().skip(from) {
+            if cells.iter().all(|&cell| !grid[cell]) {
+                cells.iter().for_each(|&cell| grid[cell] = true);
+                let next = if rest.first() == Some(&shape) {
+                    i + 1
+                } else {
+                    0
+                };
+                let placed = place(grid, placements, rest, next);
+                cells.iter().for_each(|&cell| grid[cell] = false);
+                if placed {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Every way to put each shape in the grid, as cell indices.
+    let placements: Vec<Vec<Vec<usize>>> = shapes
+        .iter()
+        .map(|shape| {
+            let mut all = Vec::new();
+            for cells in orientations(shape) {
+                for r in 0..h {
+                    for c in 0..w {
+                        if cells.iter().all(|&(dr, dc)| r + dr < h && c + dc < w) {
+                            all.push(
+                                cells
+                                    .iter()
+                                    .map(|&(dr, dc)| (r + dr) * w + c + dc)
+                                    .collect(),
+                            );
+                        }
+                    }
+                }
+            }
+            all
+        })
+        .collect();
+    let mut sorted = presents.to_vec();
+    sorted.sort_unstable();
+    place(&mut vec![false; w * h], &placements, &sorted, 0)
+}
+
+proptest! {
+    #[test]
+    fn parses_what_was_written(
+        (shapes, regions) in (1usize..=6).prop_flat_map(|n| (
+            prop::collection::vec(any::<Shape>(), n),
+            prop::collection::vec(
+                (1usize..60, 1usize..60, prop::collection::vec(0usize..40, n)),
+                0..20,
+            ),
+        ))
+    ) {
+        let input = render(&shapes, &regions);
+        let (parsed_shapes, parsed_regions) = farm::parse_input(&input).unwrap();
+        let areas: Vec<usize> = shapes.iter().map(|s| s.iter().flatten().filter(|&&c| c).count()).collect();
+
+        prop_assert_eq!(farm::shape_areas(&parsed_shapes), areas);
+        prop_assert_eq!(&parsed_regions, &regions);
+    }
+
+    #[test]
+    fn counts_match_brute_force_packing(
+        (shapes, regions) in (1usize..=3).prop_flat_map(|n| (
+            prop::collection::vec(any::<Shape>(), n),
+            prop::collection::vec(
+                (1usize..=5, 1usize..=5, prop::collection::vec(0usize..=2, n)),
+                1..6,
+            ),
+        ))
+    ) {
+        let mut brute = 0;
+        for (w, h, counts) in &regions {
+            let presents: Vec<usize> = counts
+                .iter()
+                .enumerate()
+                .flat_map(|(shape, &count)| std::iter::repeat_n(shape, count))
+                .collect();
+            let fits = packs(*w, *h, &shapes, &presents);
+            // Area is necessary, so a packable region always passes the check.
+            if fits {
+                let areas: Vec<usize> = shapes.iter().map(|s| s.iter().flatten().filter(|&&c| c).count()).collect();
+                prop_assert!(farm::can_fit_by_area(*w, *h, &areas, counts));
+            }
+            // With a 3x3 cell per present every region packs.
+            if presents.len() <= (w / 3) * (h / 3) {
+                prop_assert!(fits);
+            }
+            brute += usize::from(fits);
+        }
+
+        // The puzzle's regions are either roomy or short of area; on those the
+        // heuristic is exact.
+        let input = render(&shapes, &regions);
+        let exact = regions.iter().all(|(w, h, counts)| {
+            let presents: usize = counts.iter().sum();
+            let cells: usize = counts
+                .iter()
+                .zip(&shapes)
+                .map(|(c, s)| c * s.iter().flatten().filter(|&&c| c).count())
+                .sum();
+            presents <= (w / 3) * (h / 3) || cells > w * h
+        });
+        if exact {
+            prop_assert_eq!(farm::solve(&input).unwrap(), brute);
+        }
+    }
+}
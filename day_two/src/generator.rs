@@ -0,0 +1,24 @@
+use aoc_common::{Generator, Rng};
+
+use crate::DayTwo;
+
+impl Generator for DayTwo {
+    const SIZES: &'static [usize] = &[1_000, 10_000, 100_000, 1_000_000];
+
+    /// Ranges covering `size` ids in total, each up to 5000 ids wide and
+    /// starting at an id of 2 to 10 digits. The solver works per range, not
+    /// per id, so run time follows the number of ranges, about `size / 2500`.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut ranges = Vec::new();
+        let mut remaining = size as u64;
+        while remaining > 0 {
+            let width = rng.range(1..=remaining.min(5_000));
+            let digits = rng.range(2..=10) as u32;
+            let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            ranges.push(format!("{}-{}", start, start + width - 1));
+            remaining -= width;
+        }
+        ranges.join(",") + "\n"
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use aoc_common::Integer;
+
+/// An unsigned integer type that IDs can be counted in. Implemented for
+/// `u32`, `u64` and `u128`; the wider the type, the longer the IDs and the
+/// larger the totals it can hold.
+pub trait Id:
+    Integer
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + From<u32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn checked_ilog(self, base: Self) -> Option<u32>;
+    fn div_ceil(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_id {
+    ($($t:ty),*) => {
+        $(impl Id for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                <$t>::checked_pow(self, exp)
+            }
+
+            fn checked_ilog(self, base: Self) -> Option<u32> {
+                <$t>::checked_ilog(self, base)
+            }
+
+            fn div_ceil(self, rhs: Self) -> Self {
+                <$t>::div_ceil(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_id!(u32, u64, u128);
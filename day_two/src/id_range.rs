@@ -0,0 +1,153 @@
+use std::fmt;
+
+use std::any::type_name;
+
+use aoc_common::{ParseError, split_and_trim};
+
+use crate::invalid::{InvalidIds, InvalidTotals};
+use crate::{Id, RepetitionRule};
+
+/// The IDs `start..=end`, written `start-end` in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdRange<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Id> IdRange<T> {
+    /// `None` if `start` is after `end`.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(IdRange { start, end })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn contains(&self, id: T) -> bool {
+        (self.start..=self.end).contains(&id)
+    }
+
+    /// The IDs in both ranges, if there are any.
+    pub fn intersection(&self, other: &IdRange<T>) -> Option<IdRange<T>> {
+        IdRange::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Count and sum of the IDs in this range whose digits in `base` break
+    /// `rule`, worked out without visiting them; `None` if either does not
+    /// fit in `T`. Panics if `base` is below 2.
+    pub fn invalid_totals(&self, rule: &RepetitionRule, base: u32) -> Option<InvalidTotals<T>> {
+        InvalidTotals::in_range(*self, rule, base)
+    }
+
+    /// The IDs in this range whose digits in `base` break `rule`, in
+    /// ascending order. Panics if `base` is below 2.
+    pub fn invalid_ids(&self, rule: &RepetitionRule, base: u32) -> InvalidIds<T> {
+        InvalidIds::new(*self, rule.clone(), base)
+    }
+}
+
+impl<T: Id> fmt::Display for IdRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Two input ranges that share IDs: their positions in the input and the
+/// IDs they share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap<T> {
+    pub first: usize,
+    pub second: usize,
+    pub shared: IdRange<T>,
+}
+
+/// Sort `ranges` and merge any that share IDs, so every ID is in at most one
+/// range.
+pub fn merge_ranges<T: Id>(mut ranges: Vec<IdRange<T>>) -> Vec<IdRange<T>> {
+    ranges.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<IdRange<T>> = Vec::new();
+    for range in ranges {
+        if let Some(last) = merged.last_mut()
+            && range.start <= last.end
+        {
+            last.end = last.end.max(range.end);
+            continue;
+        }
+        merged.push(range);
+    }
+
+    merged
+}
+
+/// Every pair of `ranges` that shares IDs, ordered by where the shared IDs
+/// start. `first` is always the earlier of the pair in `ranges`.
+pub fn overlaps<T: Id>(ranges: &[IdRange<T>]) -> Vec<Overlap<T>> {
+    let mut order: Vec<usize> = (0..ranges.len()).collect();
+    order.sort_by_key(|&i| ranges[i].start);
+
+    let mut found = Vec::new();
+    // Ranges seen so far that reach the current start.
+    let mut open: Vec<usize> = Vec::new();
+    for i in order {
+        let range = ranges[i];
+        open.retain(|&j| ranges[j].end >= range.start);
+        for &j in &open {
+            if let Some(shared) = ranges[j].intersection(&range) {
+                found.push(Overlap {
+                    first: i.min(j),
+                    second: i.max(j),
+                    shared,
+                });
+            }
+        }
+        open.push(i);
+    }
+
+    found.sort_by_key(|o| (o.shared.start, o.first, o.second));
+    found
+}
+
+/// Parse comma-separated `start-end` ranges.
+///
+/// Bounds are plain decimal digits that fit in `T`; signs, empty bounds, reversed ranges and
+/// empty entries between commas are errors. Whitespace around a range or a
+/// bound is ignored, so the ranges may be wrapped across lines.
+pub fn parse_ranges<T: Id>(input: &str) -> Result<Vec<IdRange<T>>, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(vec![]);
+    }
+
+    split_and_trim(trimmed, ',')
+        .map(|range| parse_range(input, range))
+        .collect()
+}
+
+fn parse_range<T: Id>(input: &str, range: &str) -> Result<IdRange<T>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::in_input(input, range, "a range 'start-end'"))?;
+    let start = parse_bound(input, start)?;
+    let end = parse_bound(input, end)?;
+    IdRange::new(start, end).ok_or_else(|| {
+        ParseError::in_input(input, range, "a range whose start is not after its end")
+    })
+}
+
+fn parse_bound<T: Id>(input: &str, bound: &str) -> Result<T, ParseError> {
+    let bound = bound.trim();
+    if bound.is_empty() || !bound.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::in_input(input, bound, "a non-negative id"));
+    }
+    // Only digits are left, so the one way to fail is overflow.
+    bound.parse().map_err(|_| {
+        let expected = format!("a non-negative id that fits in {}", type_name::<T>());
+        ParseError::in_input(input, bound, expected)
+    })
+}
@@ -0,0 +1,243 @@
+//! Invalid IDs worked out from their shape instead of by testing every ID.
+//!
+//! An `n`-digit ID in base `b` that is a `d`-digit block `p` repeated is
+//! `p * (b^n - 1) / (b^d - 1)`, so for each ID length and block length the
+//! invalid IDs in a range form an arithmetic series in `p`.
+
+use crate::{Id, IdRange, RepetitionRule};
+
+/// How many invalid IDs a range holds and what they add up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTotals<T> {
+    pub count: T,
+    pub sum: T,
+}
+
+impl<T: Id> InvalidTotals<T> {
+    const ZERO: Self = InvalidTotals {
+        count: T::ZERO,
+        sum: T::ZERO,
+    };
+
+    /// `None` if the count or the sum does not fit in `T`.
+    ///
+    /// For each ID length the invalid IDs are the union of the series for
+    /// each allowed block length. IDs that repeat with several block lengths
+    /// (`111111` is `1` six times, `11` three times and `111` twice) are
+    /// counted once by splitting the union by each ID's shortest block: the
+    /// IDs whose shortest block has `m` digits are those repeating an
+    /// `m`-digit block less those whose shortest block divides `m`. Every
+    /// partial total is part of the union, so the arithmetic only overflows
+    /// if the result does. The work is proportional to the number of digit
+    /// lengths in the range.
+    pub(crate) fn in_range(range: IdRange<T>, rule: &RepetitionRule, base: u32) -> Option<Self> {
+        let digits = Digits::new(base);
+        let mut totals = InvalidTotals::ZERO;
+
+        for (len, lo, hi) in digits.lengths(range) {
+            let blocks = rule.block_lengths(len);
+            // Every shortest block of an invalid ID divides an allowed one.
+            let shortest: Vec<u32> = (1..=len)
+                .filter(|&m| blocks.iter().any(|&block| block % m == 0))
+                .collect();
+            let mut exact: Vec<InvalidTotals<T>> = Vec::with_capacity(shortest.len());
+            for &m in &shortest {
+                let mut with_m = Blocks::new(&digits, lo, hi, len, m).totals()?;
+                for (&shorter, less) in shortest.iter().zip(&exact) {
+                    if shorter < m && m % shorter == 0 {
+                        with_m = with_m.checked_sub(*less)?;
+                    }
+                }
+                totals = totals.checked_add(with_m)?;
+                exact.push(with_m);
+            }
+        }
+
+        Some(totals)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(InvalidTotals {
+            count: self.count.checked_add(other.count)?,
+            sum: self.sum.checked_add(other.sum)?,
+        })
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(InvalidTotals {
+            count: self.count.checked_sub(other.count)?,
+            sum: self.sum.checked_sub(other.sum)?,
+        })
+    }
+}
+
+/// The invalid IDs of one range in ascending order, produced lazily.
+///
+/// Within one ID length each allowed block length gives an ascending
+/// sequence; the iterator merges them and skips IDs several produce.
+#[derive(Debug, Clone)]
+pub struct InvalidIds<T> {
+    rule: RepetitionRule,
+    digits: Digits<T>,
+    lengths: Lengths<T>,
+    blocks: Vec<Blocks<T>>,
+}
+
+impl<T: Id> InvalidIds<T> {
+    pub(crate) fn new(range: IdRange<T>, rule: RepetitionRule, base: u32) -> Self {
+        let digits = Digits::new(base);
+        InvalidIds {
+            rule,
+            lengths: digits.lengths(range),
+            digits,
+            blocks: Vec::new(),
+        }
+    }
+}
+
+impl<T: Id> Iterator for InvalidIds<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let smallest = self.blocks.iter().filter_map(Blocks::peek).min();
+            if let Some(id) = smallest {
+                for blocks in &mut self.blocks {
+                    if blocks.peek() == Some(id) {
+                        blocks.advance();
+                    }
+                }
+                return Some(id);
+            }
+
+            let (len, lo, hi) = self.lengths.next()?;
+            self.blocks = self
+                .rule
+                .block_lengths(len)
+                .into_iter()
+                .map(|block| Blocks::new(&self.digits, lo, hi, len, block))
+                .collect();
+        }
+    }
+}
+
+/// The `len`-digit IDs in a range that are one `block`-digit pattern,
+/// without a leading zero, repeated: `pattern * repunit` for each pattern
+/// in `first..=last`.
+#[derive(Debug, Clone)]
+struct Blocks<T> {
+    first: T,
+    last: T,
+    repunit: T,
+}
+
+impl<T: Id> Blocks<T> {
+    const EMPTY: Self = Blocks {
+        first: T::ONE,
+        last: T::ZERO,
+        repunit: T::ONE,
+    };
+
+    fn new(digits: &Digits<T>, lo: T, hi: T, len: u32, block: u32) -> Self {
+        // `1 + b^block + b^(2 * block) + ...`, built up term by term since
+        // `b^len` itself may not fit. If the repunit does not, neither does
+        // any ID it makes.
+        let mut repunit = T::ZERO;
+        for i in 0..len / block {
+            let term = digits.pow(block * i).expect("below a len-digit ID");
+            match repunit.checked_add(term) {
+                Some(sum) => repunit = sum,
+                None => return Blocks::EMPTY,
+            }
+        }
+        // No leading zero, except for the one-digit ID 0 itself.
+        let smallest_pattern = if len == 1 {
+            T::ZERO
+        } else {
+            digits.pow(block - 1).expect("below a len-digit ID")
+        };
+        let largest_pattern = digits.pow(block).map_or(T::MAX, |p| p - T::ONE);
+        Blocks {
+            first: smallest_pattern.max(lo.div_ceil(repunit)),
+            last: largest_pattern.min(hi / repunit),
+            repunit,
+        }
+    }
+
+    fn peek(&self) -> Option<T> {
+        // `last * repunit` is at most the range's end, so this fits.
+        (self.first <= self.last).then(|| self.first * self.repunit)
+    }
+
+    fn advance(&mut self) {
+        if self.first < self.last {
+            self.first = self.first + T::ONE;
+        } else {
+            *self = Blocks::EMPTY;
+        }
+    }
+
+    /// Count and sum of the remaining IDs, or `None` if either overflows.
+    fn totals(&self) -> Option<InvalidTotals<T>> {
+        if self.first > self.last {
+            return Some(InvalidTotals::ZERO);
+        }
+        let count = (self.last - self.first).checked_add(T::ONE)?;
+        // The patterns are `first + i` for `i` below `count`; of `count` and
+        // `count - 1`, one is even.
+        let two = T::from(2);
+        let steps = if count % two == T::ZERO {
+            (count / two).checked_mul(count - T::ONE)?
+        } else {
+            count.checked_mul((count - T::ONE) / two)?
+        };
+        let pattern_sum = count.checked_mul(self.first)?.checked_add(steps)?;
+        Some(InvalidTotals {
+            count,
+            sum: pattern_sum.checked_mul(self.repunit)?,
+        })
+    }
+}
+
+/// Digit arithmetic in one base.
+#[derive(Debug, Clone)]
+struct Digits<T> {
+    base: T,
+}
+
+type Lengths<T> = std::vec::IntoIter<(u32, T, T)>;
+
+impl<T: Id> Digits<T> {
+    fn new(base: u32) -> Self {
+        assert!(base >= 2, "base {} has no digits to repeat", base);
+        Digits {
+            base: T::from(base),
+        }
+    }
+
+    fn pow(&self, exp: u32) -> Option<T> {
+        self.base.checked_pow(exp)
+    }
+
+    fn count(&self, n: T) -> u32 {
+        n.checked_ilog(self.base).unwrap_or(0) + 1
+    }
+
+    /// Each digit length in `range` with the part of the range that has it.
+    fn lengths(&self, range: IdRange<T>) -> Lengths<T> {
+        (self.count(range.start())..=self.count(range.end()))
+            .map(|len| {
+                // Both powers are at most the range's end, bar the last
+                // length's upper one, which may be past `T::MAX`.
+                let min = if len == 1 {
+                    T::ZERO
+                } else {
+                    self.pow(len - 1).expect("at most the range's end")
+                };
+                let max = self.pow(len).map_or(T::MAX, |p| p - T::ONE);
+                (len, range.start().max(min), range.end().min(max))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
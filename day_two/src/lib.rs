@@ -1,81 +1,117 @@
-use aoc_common as common;
+use std::error::Error;
+use std::fmt;
 
-pub fn sum_invalid_ids(input: &str, part: u8, debug: bool) -> i64 {
-    let id_ranges = common::split_and_trim(input, ',');
+use anyhow::Result;
+use aoc_common::{OverflowError, ParseError, Part, Solution};
 
-    let mut sum: i64 = 0;
+mod generator;
+mod id;
+mod id_range;
+mod invalid;
+mod rule;
 
-    for range in id_ranges {
-        let bounds: Vec<&str> = range.split('-').collect();
-        if bounds.len() != 2 {
-            continue; // skip malformed range
-        }
+pub use id::Id;
+pub use id_range::{IdRange, Overlap, merge_ranges, overlaps, parse_ranges};
+pub use invalid::{InvalidIds, InvalidTotals};
+pub use rule::RepetitionRule;
 
-        let lower: i64 = match bounds[0].trim().parse() {
-            Ok(v) => v,
-            Err(_) => continue,
-        };
-        let upper: i64 = match bounds[1].trim().parse() {
-            Ok(v) => v,
-            Err(_) => continue,
-        };
+/// The puzzle's IDs are decimal.
+pub const BASE: u32 = 10;
 
-        if lower > upper {
-            continue;
-        }
+pub struct DayTwo;
 
-        for id in lower..=upper {
-            let s = id.to_string();
-            let invalid = match part {
-                1 => has_repeated_pattern_twice(&s),
-                _ => has_more_than_two_repeated_patterns(&s),
-            };
-
-            if invalid {
-                if debug {
-                    println!("Invalid ID found: {} (range {}-{})", id, lower, upper);
-                }
-                sum = sum.saturating_add(id);
-            }
-        }
+impl Solution for DayTwo {
+    const DAY: u8 = 2;
+    type Input = Vec<IdRange<u128>>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(merge_ranges(parse_ranges(input)?))
     }
 
-    sum
-}
+    fn part_one(ranges: &Self::Input) -> Result<u128> {
+        Ok(total_invalid(ranges, Part::One)?)
+    }
 
-pub fn has_repeated_pattern_twice(num_str: &str) -> bool {
-    let len = num_str.len();
-    if len == 0 || !len.is_multiple_of(2) {
-        return false;
+    fn part_two(ranges: &Self::Input) -> Result<u128> {
+        Ok(total_invalid(ranges, Part::Two)?)
     }
+}
 
-    let half = len / 2;
-    num_str[..half] == num_str[half..]
+/// Why the invalid IDs of an input could not be summed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SumError {
+    Parse(ParseError),
+    Overflow(OverflowError),
 }
 
-pub fn has_more_than_two_repeated_patterns(num_str: &str) -> bool {
-    let len = num_str.len();
-    if len == 0 {
-        return false;
+impl fmt::Display for SumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SumError::Parse(e) => e.fmt(f),
+            SumError::Overflow(e) => e.fmt(f),
+        }
     }
+}
 
-    for sub_len in 1..=len / 2 {
-        if !len.is_multiple_of(sub_len) {
-            continue;
-        }
+impl Error for SumError {}
 
-        let times = len / sub_len;
-        if times < 2 {
-            continue;
-        }
+impl From<ParseError> for SumError {
+    fn from(e: ParseError) -> Self {
+        SumError::Parse(e)
+    }
+}
 
-        let pattern = &num_str[..sub_len];
-        if pattern.repeat(times) == num_str {
-            return true;
-        }
+impl From<OverflowError> for SumError {
+    fn from(e: OverflowError) -> Self {
+        SumError::Overflow(e)
     }
+}
 
-    false
+/// Sum the invalid IDs in the ranges of `input`: IDs made of one block of
+/// digits repeated exactly twice for part 1, at least twice for part 2. An ID
+/// in several overlapping ranges counts once; see [`overlaps`] to list them.
+///
+/// IDs and the sum are `T`s; a bound that does not fit is a parse error and a
+/// sum that does not fit an overflow error.
+pub fn sum_invalid_ids<T: Id>(input: &str, part: Part) -> Result<T, SumError> {
+    Ok(total_invalid(&merge_ranges(parse_ranges(input)?), part)?)
+}
+
+/// Sum the invalid IDs in `ranges`, which should not overlap.
+fn total_invalid<T: Id>(ranges: &[IdRange<T>], part: Part) -> Result<T, OverflowError> {
+    let rule = RepetitionRule::from(part);
+    ranges.iter().try_fold(T::ZERO, |sum, range| {
+        range
+            .invalid_totals(&rule, BASE)
+            .and_then(|totals| sum.checked_add(totals.sum))
+            .ok_or_else(|| {
+                OverflowError::new::<T>(format!("the sum of invalid ids up to range {}", range))
+            })
+    })
+}
+
+/// Every ID breaking `rule` in `base` paired with the range it was found in,
+/// range by range and ascending within each range.
+pub fn invalid_ids<'a, T: Id>(
+    ranges: &'a [IdRange<T>],
+    rule: &'a RepetitionRule,
+    base: u32,
+) -> impl Iterator<Item = (IdRange<T>, T)> + 'a {
+    ranges
+        .iter()
+        .flat_map(move |&range| range.invalid_ids(rule, base).map(move |id| (range, id)))
+}
+
+/// Part 1's rule for a single ID: one block of digits repeated exactly twice.
+pub fn has_repeated_pattern_twice(num_str: &str) -> bool {
+    RepetitionRule::from(Part::One).matches_digits(num_str.as_bytes())
+}
+
+/// Part 2's rule for a single ID: one block of digits repeated at least twice.
+pub fn has_more_than_two_repeated_patterns(num_str: &str) -> bool {
+    RepetitionRule::from(Part::Two).matches_digits(num_str.as_bytes())
 }
 
 #[cfg(test)]
@@ -99,6 +135,270 @@ mod tests {
     #[test]
     fn sum_invalid_ids_small_range() {
         let input = "10-12,1212-1212";
-        assert_eq!(sum_invalid_ids(input, 2, false), 1223);
+        assert_eq!(sum_invalid_ids::<u64>(input, Part::Two), Ok(1223));
+    }
+
+    fn range(start: u64, end: u64) -> IdRange<u64> {
+        IdRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn totals_without_visiting_ids() {
+        // 11, 22, ..., 99.
+        let two_digit = InvalidTotals { count: 9, sum: 495 };
+        assert_eq!(
+            range(0, 99).invalid_totals(&Part::One.into(), BASE),
+            Some(two_digit)
+        );
+        assert_eq!(
+            range(0, 99).invalid_totals(&Part::Two.into(), BASE),
+            Some(two_digit)
+        );
+
+        // 111111 repeats with blocks of 1, 2 and 3 digits but counts once.
+        assert_eq!(
+            range(111_111, 111_111).invalid_totals(&Part::Two.into(), BASE),
+            Some(InvalidTotals {
+                count: 1,
+                sum: 111_111
+            })
+        );
+        assert_eq!(
+            range(111_111, 111_111)
+                .invalid_totals(&Part::One.into(), BASE)
+                .unwrap()
+                .count,
+            1
+        );
+        assert_eq!(
+            range(111, 111)
+                .invalid_totals(&Part::One.into(), BASE)
+                .unwrap()
+                .count,
+            0
+        );
+
+        // Every 6-digit ID: 900 halves plus 90 blocks of 2 digits, minus the
+        // 9 blocks of 1 digit that are both.
+        let six_digit = range(100_000, 999_999)
+            .invalid_totals(&Part::Two.into(), BASE)
+            .unwrap();
+        assert_eq!(six_digit.count, 900 + 90 - 9);
+    }
+
+    #[test]
+    fn widest_ranges_are_exact() {
+        let wide = |start, end| IdRange::<u128>::new(start, end).unwrap();
+        let totals = |range: IdRange<u128>| range.invalid_totals(&Part::Two.into(), BASE).unwrap();
+        let max = u128::from(u64::MAX);
+        let mid = 10_000_000_000_000_000_000;
+        let (all, low, high) = (
+            totals(wide(0, max)),
+            totals(wide(0, mid - 1)),
+            totals(wide(mid, max)),
+        );
+        assert_eq!(all.count, low.count + high.count);
+        assert_eq!(all.sum, low.sum + high.sum);
+        // 20-digit halves run from 1000000000 to 1844674407.
+        assert_eq!(
+            range(mid as u64, u64::MAX)
+                .invalid_totals(&Part::One.into(), BASE)
+                .map(|totals| totals.count),
+            None
+        );
+        assert_eq!(
+            wide(mid, max)
+                .invalid_totals(&Part::One.into(), BASE)
+                .unwrap()
+                .count,
+            844_674_408
+        );
+
+        let last: Vec<u64> = range(u64::MAX - 10_000_000_000, u64::MAX)
+            .invalid_ids(&Part::Two.into(), BASE)
+            .collect();
+        assert_eq!(last, vec![18_446_744_071_844_674_407]);
+    }
+
+    #[test]
+    fn iterates_invalid_ids_with_their_range() {
+        let ranges = parse_ranges("95-115,998-1012,1188511880-1188511890").unwrap();
+        let found: Vec<(String, u64)> = invalid_ids(&ranges, &Part::Two.into(), BASE)
+            .map(|(range, id)| (range.to_string(), id))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("95-115".to_string(), 99),
+                ("95-115".to_string(), 111),
+                ("998-1012".to_string(), 999),
+                ("998-1012".to_string(), 1010),
+                ("1188511880-1188511890".to_string(), 1188511885),
+            ]
+        );
+        let part_one: Vec<u64> = invalid_ids(&ranges, &Part::One.into(), BASE)
+            .map(|(_, id)| id)
+            .collect();
+        assert_eq!(part_one, vec![99, 1010, 1188511885]);
+    }
+
+    #[test]
+    fn rules_in_other_bases() {
+        let three_times = RepetitionRule::Exactly(3);
+        assert!(three_times.matches(0xabcabcabc_u64, 16));
+        assert!(three_times.matches(0xababab_u64, 16));
+        assert!(!three_times.matches(0xabab_u64, 16));
+        // 0b111111 is 11 three times.
+        assert!(three_times.matches(0b111111_u64, 2));
+        assert!(!three_times.matches(0b110110_u64, 2));
+
+        // Three hex digits, one digit three times: 0x111 to 0xfff.
+        let totals = range(0x100, 0xfff)
+            .invalid_totals(&three_times, 16)
+            .unwrap();
+        assert_eq!(totals.count, 15);
+        assert_eq!(totals.sum, (1..16).map(|d| d * 0x111).sum::<u64>());
+
+        let two_or_three = RepetitionRule::OneOf(vec![2, 3]);
+        // 121121 is 121 twice, 121212 is 12 three times and 111111 is both.
+        let ids: Vec<u64> = range(111_000, 121_300)
+            .invalid_ids(&two_or_three, BASE)
+            .collect();
+        assert_eq!(ids[..3], [111_111, 112_112, 113_113]);
+        assert_eq!(ids[ids.len() - 3..], [120_120, 121_121, 121_212]);
+        assert_eq!(ids.len(), 12);
+
+        assert!(RepetitionRule::AtLeast(1).matches(7_u64, BASE));
+        assert!(!RepetitionRule::OneOf(vec![]).matches(77_u64, BASE));
+        assert_eq!(
+            range(0, 9)
+                .invalid_totals(&RepetitionRule::Exactly(1), BASE)
+                .unwrap()
+                .count,
+            10
+        );
+    }
+
+    #[test]
+    fn overlapping_ranges_count_ids_once() {
+        // 11 and 22 are in both of the first two ranges; 99 only in the last.
+        let input = "10-30,5-25,99-99";
+        assert_eq!(sum_invalid_ids::<u64>(input, Part::Two), Ok(11 + 22 + 99));
+
+        let ranges = parse_ranges(input).unwrap();
+        assert_eq!(merge_ranges(ranges.clone()), [range(5, 30), range(99, 99)]);
+        assert_eq!(
+            overlaps(&ranges),
+            [Overlap {
+                first: 0,
+                second: 1,
+                shared: range(10, 25)
+            }]
+        );
+
+        // Touching ranges share their end point; separate ones share nothing.
+        let ranges = [range(1, 5), range(5, 9), range(10, 12), range(2, 3)];
+        assert_eq!(
+            overlaps(&ranges),
+            [
+                Overlap {
+                    first: 0,
+                    second: 3,
+                    shared: range(2, 3)
+                },
+                Overlap {
+                    first: 0,
+                    second: 1,
+                    shared: range(5, 5)
+                },
+            ]
+        );
+        assert_eq!(merge_ranges(ranges.to_vec()), [range(1, 9), range(10, 12)]);
+    }
+
+    #[test]
+    fn malformed_ranges_are_errors() {
+        let err = sum_invalid_ids::<u64>("10-12,1212", Part::One).unwrap_err();
+        let expected = ParseError::new(1, 7, "1212", "a range 'start-end'");
+        assert_eq!(err, SumError::Parse(expected));
+
+        let Err(SumError::Parse(err)) = sum_invalid_ids::<u64>("10-1x", Part::One) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.column, err.text.as_str()), (4, "1x"));
+
+        assert!(sum_invalid_ids::<u64>("12-10", Part::One).is_err());
+        assert_eq!(sum_invalid_ids::<u64>("\n", Part::One), Ok(0));
+    }
+
+    #[test]
+    fn bounds_are_plain_digits() {
+        for (input, column, text, expected) in [
+            ("+5-10", 1, "+5", "a non-negative id"),
+            ("5--10", 3, "-10", "a non-negative id"),
+            ("5-", 3, "", "a non-negative id"),
+            ("1-2,,3-4", 5, "", "a range 'start-end'"),
+            ("1-2,", 5, "", "a range 'start-end'"),
+            ("1-2 3", 3, "2 3", "a non-negative id"),
+            (
+                "1-18446744073709551616",
+                3,
+                "18446744073709551616",
+                "a non-negative id that fits in u64",
+            ),
+        ] {
+            let err = parse_ranges::<u64>(input).unwrap_err();
+            assert_eq!(err, ParseError::new(1, column, text, expected), "{input}");
+        }
+
+        let ranges = parse_ranges(" 1-2,\n3 - 4 ,\n5-18446744073709551615\n").unwrap();
+        assert_eq!(ranges[1], range(3, 4));
+        assert_eq!(ranges[2].end(), u64::MAX);
+    }
+
+    #[test]
+    fn ids_past_u64() {
+        // 25-digit IDs: only 10000 repeated five times is invalid.
+        let input = "1000000000000000000000000-1000100000000000000000000";
+        assert!(sum_invalid_ids::<u64>(input, Part::Two).is_err());
+        assert_eq!(sum_invalid_ids::<u128>(input, Part::One), Ok(0));
+        assert_eq!(
+            sum_invalid_ids::<u128>(input, Part::Two),
+            Ok(1_000_010_000_100_001_000_010_000)
+        );
+        assert_eq!(
+            DayTwo::part_two(&DayTwo::parse(input).unwrap()).unwrap(),
+            1_000_010_000_100_001_000_010_000
+        );
+    }
+
+    #[test]
+    fn sums_past_the_id_type_are_errors() {
+        let err = sum_invalid_ids::<u32>("2222222222-3333333333", Part::Two).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the sum of invalid ids up to range 2222222222-3333333333 does not fit in u32"
+        );
+
+        // Each range's sum fits in a u32, but not both together.
+        let input = "1-2,\n3000030000-3000030000, 4000040000-4000040000";
+        let err = sum_invalid_ids::<u32>(input, Part::One).unwrap_err();
+        assert_eq!(
+            err,
+            SumError::Overflow(OverflowError::new::<u32>(
+                "the sum of invalid ids up to range 4000040000-4000040000"
+            ))
+        );
+        assert_eq!(sum_invalid_ids::<u64>(input, Part::One), Ok(7_000_070_000));
+
+        // The solution reports the same error as `sum_invalid_ids`.
+        let input = "1-99999999999999999999999999999999999999";
+        let err = DayTwo::part_two(&DayTwo::parse(input).unwrap()).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<OverflowError>(),
+            Some(&OverflowError::new::<u128>(
+                "the sum of invalid ids up to range 1-99999999999999999999999999999999999999"
+            ))
+        );
     }
 }
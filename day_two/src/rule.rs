@@ -0,0 +1,79 @@
+use aoc_common::Part;
+
+use crate::Id;
+
+/// Which repeat counts make an ID invalid. An ID is invalid when its digits
+/// are some block repeated `k` times for an allowed `k`, so `111111` is
+/// invalid under `Exactly(3)` (`11` three times) as well as `Exactly(2)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepetitionRule {
+    Exactly(u32),
+    AtLeast(u32),
+    OneOf(Vec<u32>),
+}
+
+impl RepetitionRule {
+    pub fn allows(&self, repeats: u32) -> bool {
+        match self {
+            RepetitionRule::Exactly(k) => repeats == *k,
+            RepetitionRule::AtLeast(k) => repeats >= *k,
+            RepetitionRule::OneOf(ks) => ks.contains(&repeats),
+        }
+    }
+
+    /// Whether `id`, written in `base`, is a block repeated an allowed number
+    /// of times. Panics if `base` is below 2.
+    pub fn matches<T: Id>(&self, id: T, base: u32) -> bool {
+        assert!(base >= 2, "base {} has no digits to repeat", base);
+        let base = T::from(base);
+        let mut digits = Vec::new();
+        let mut n = id;
+        loop {
+            digits.push(n % base);
+            n = n / base;
+            if n == T::ZERO {
+                break;
+            }
+        }
+        self.matches_digits(&digits)
+    }
+
+    /// Whether `digits` are a block repeated an allowed number of times. The
+    /// digits can be in any base or order, as long as it is the same one
+    /// throughout.
+    pub fn matches_digits<T: PartialEq>(&self, digits: &[T]) -> bool {
+        let len = digits.len();
+        (1..=len).any(|repeats| {
+            len.is_multiple_of(repeats)
+                && self.allows(repeats as u32)
+                && digits
+                    .chunks(len / repeats)
+                    .all(|block| block == &digits[..len / repeats])
+        })
+    }
+
+    /// Block lengths that make an `len`-digit ID invalid, leaving out any
+    /// that divide another: repeating with the shorter block implies
+    /// repeating with the longer one.
+    pub(crate) fn block_lengths(&self, len: u32) -> Vec<u32> {
+        let blocks: Vec<u32> = (1..=len)
+            .filter(|&repeats| len.is_multiple_of(repeats) && self.allows(repeats))
+            .map(|repeats| len / repeats)
+            .collect();
+        blocks
+            .iter()
+            .copied()
+            .filter(|&b| !blocks.iter().any(|&longer| longer != b && longer % b == 0))
+            .collect()
+    }
+}
+
+/// Part 1 is a block repeated exactly twice, part 2 at least twice.
+impl From<Part> for RepetitionRule {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => RepetitionRule::Exactly(2),
+            Part::Two => RepetitionRule::AtLeast(2),
+        }
+    }
+}
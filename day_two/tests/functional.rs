@@ -1,3 +1,4 @@
+use aoc_common::Part;
 use day_two as lib;
 
 #[test]
@@ -9,5 +10,5 @@ fn patterns_and_sum() {
     assert!(lib::has_more_than_two_repeated_patterns("1212"));
 
     let input = "10-12,1212-1212";
-    assert_eq!(lib::sum_invalid_ids(input, 2, false), 1223);
+    assert_eq!(lib::sum_invalid_ids::<u64>(input, Part::Two), Ok(1223));
 }
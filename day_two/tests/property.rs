@@ -0,0 +1,197 @@
+use std::collections::BTreeSet;
+
+use aoc_common::Part;
+use day_two::{IdRange, RepetitionRule};
+use proptest::prelude::*;
+
+/// Does `s` consist of its first `len` bytes repeated?
+fn repeats(s: &str, len: usize) -> bool {
+    s.len().is_multiple_of(len) && s == s[..len].repeat(s.len() / len)
+}
+
+/// Digits of `id` in `base`, most significant first.
+fn digits(mut id: u64, base: u64) -> Vec<u64> {
+    let mut out = vec![id % base];
+    while id >= base {
+        id /= base;
+        out.push(id % base);
+    }
+    out.reverse();
+    out
+}
+
+/// Is `id` in `base` some block repeated `k` times for a `k` that `allowed`?
+fn repeated(id: u64, base: u64, allowed: impl Fn(usize) -> bool) -> bool {
+    let d = digits(id, base);
+    (1..=d.len())
+        .any(|k| d.len().is_multiple_of(k) && allowed(k) && d == d[..d.len() / k].repeat(k))
+}
+
+fn rule() -> impl Strategy<Value = RepetitionRule> {
+    prop_oneof![
+        (1u32..6).prop_map(RepetitionRule::Exactly),
+        (1u32..6).prop_map(RepetitionRule::AtLeast),
+        prop::collection::vec(1u32..8, 0..4).prop_map(RepetitionRule::OneOf),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn patterns_match_repeat_search(id in 0u64..100_000_000_000u64) {
+        let s = id.to_string();
+        let twice = s.len() % 2 == 0 && repeats(&s, s.len() / 2);
+        let any = (1..s.len()).any(|len| repeats(&s, len));
+
+        prop_assert_eq!(day_two::has_repeated_pattern_twice(&s), twice);
+        prop_assert_eq!(day_two::has_more_than_two_repeated_patterns(&s), any);
+    }
+
+    #[test]
+    fn sums_match_filtering_every_id(
+        ranges in prop::collection::vec((1u64..1_000_000, 0u64..300), 1..5)
+    ) {
+        let input: Vec<String> = ranges.iter().map(|(lo, w)| format!("{}-{}", lo, lo + w)).collect();
+        let input = input.join(",");
+        // Ranges may overlap; an ID in several of them counts once.
+        let ids: BTreeSet<u64> = ranges.iter().flat_map(|&(lo, w)| lo..=lo + w).collect();
+        let sum = |invalid: fn(&str) -> bool| ids.iter().filter(|id| invalid(&id.to_string())).sum::<u64>();
+
+        prop_assert_eq!(
+            day_two::sum_invalid_ids::<u64>(&input, Part::One).unwrap(),
+            sum(|s| s.len() % 2 == 0 && repeats(s, s.len() / 2))
+        );
+        prop_assert_eq!(
+            day_two::sum_invalid_ids::<u64>(&input, Part::Two).unwrap(),
+            sum(|s| (1..s.len()).any(|len| repeats(s, len)))
+        );
+    }
+
+    #[test]
+    fn totals_match_filtering_across_digit_lengths(
+        digits in 1u32..12,
+        before in 0u64..2000,
+        after in 0u64..2000,
+    ) {
+        let boundary = 10u64.pow(digits);
+        let (lo, hi) = (boundary.saturating_sub(before), boundary + after);
+        let range = IdRange::new(lo, hi).unwrap();
+        for part in Part::BOTH {
+            let invalid: Vec<u64> = (lo..=hi)
+                .filter(|id| {
+                    let s = id.to_string();
+                    match part {
+                        Part::One => s.len() % 2 == 0 && repeats(&s, s.len() / 2),
+                        Part::Two => (1..s.len()).any(|len| repeats(&s, len)),
+                    }
+                })
+                .collect();
+            let totals = range.invalid_totals(&part.into(), day_two::BASE).unwrap();
+            prop_assert_eq!(totals.count, invalid.len() as u64);
+            prop_assert_eq!(totals.sum, invalid.iter().sum::<u64>());
+            prop_assert_eq!(range.invalid_ids(&part.into(), day_two::BASE).collect::<Vec<_>>(), invalid);
+        }
+    }
+
+    #[test]
+    fn any_rule_and_base_match_filtering(
+        rule in rule(),
+        base in 2u32..17,
+        start in 0u64..200_000,
+        width in 0u64..3000,
+    ) {
+        let allowed = |k: usize| rule.allows(k as u32);
+        let range = IdRange::new(start, start + width).unwrap();
+        let invalid: Vec<u64> = (start..=start + width)
+            .filter(|&id| repeated(id, u64::from(base), allowed))
+            .collect();
+        for id in start..=start + width {
+            prop_assert_eq!(rule.matches(id, base), invalid.contains(&id));
+        }
+
+        let totals = range.invalid_totals(&rule, base).unwrap();
+        prop_assert_eq!(totals.count, invalid.len() as u64);
+        prop_assert_eq!(totals.sum, invalid.iter().sum::<u64>());
+        prop_assert_eq!(range.invalid_ids(&rule, base).collect::<Vec<_>>(), invalid);
+    }
+
+    #[test]
+    fn overlapping_ranges_sum_as_a_set(
+        bounds in prop::collection::vec((0u64..5000, 0u64..500), 0..8),
+        part in prop_oneof![Just(Part::One), Just(Part::Two)],
+    ) {
+        let ranges: Vec<IdRange<u64>> = bounds
+            .iter()
+            .map(|&(start, width)| IdRange::new(start, start + width).unwrap())
+            .collect();
+        let input = ranges.iter().map(IdRange::to_string).collect::<Vec<_>>().join(",");
+
+        let ids: BTreeSet<u64> = ranges.iter().flat_map(|r| r.start()..=r.end()).collect();
+        let rule = RepetitionRule::from(part);
+        let expected: u64 = ids.iter().filter(|&&id| rule.matches(id, day_two::BASE)).sum();
+        prop_assert_eq!(day_two::sum_invalid_ids(&input, part), Ok(expected));
+
+        let merged = day_two::merge_ranges(ranges.clone());
+        let merged_ids: BTreeSet<u64> = merged.iter().flat_map(|r| r.start()..=r.end()).collect();
+        prop_assert_eq!(&merged_ids, &ids);
+        prop_assert!(merged.windows(2).all(|w| w[0].end() < w[1].start()));
+
+        let reported = day_two::overlaps(&ranges);
+        let mut pairs = 0;
+        for (i, a) in ranges.iter().enumerate() {
+            for (j, b) in ranges.iter().enumerate().skip(i + 1) {
+                let shared = reported
+                    .iter()
+                    .find(|o| (o.first, o.second) == (i, j))
+                    .map(|o| o.shared);
+                prop_assert_eq!(shared, a.intersection(b));
+                pairs += usize::from(shared.is_some());
+            }
+        }
+        prop_assert_eq!(reported.len(), pairs);
+    }
+
+    #[test]
+    fn narrow_totals_fail_only_when_they_overflow(
+        rule in rule(),
+        base in 2u32..17,
+        start in any::<u32>(),
+        width in any::<u32>(),
+    ) {
+        let end = start.saturating_add(width);
+        let narrow = IdRange::new(start, end).unwrap().invalid_totals(&rule, base);
+        let wide = IdRange::new(u128::from(start), u128::from(end))
+            .unwrap()
+            .invalid_totals(&rule, base)
+            .unwrap();
+        let fits = |n: u128| u32::try_from(n).ok();
+        let expected = fits(wide.count)
+            .zip(fits(wide.sum))
+            .map(|(count, sum)| day_two::InvalidTotals { count, sum });
+        prop_assert_eq!(narrow, expected);
+    }
+
+    #[test]
+    fn ids_past_u64_match_the_iterator(
+        digits in 19u32..39,
+        offset in 0u128..1_000_000_000_000,
+        width in 0u128..1_000_000_000_000_000,
+        rule in rule(),
+    ) {
+        let start = 10u128.pow(digits) - offset;
+        let range = IdRange::new(start, start + width).unwrap();
+        // Some rules make most IDs invalid; check the first few of those.
+        let ids: Vec<u128> = range.invalid_ids(&rule, day_two::BASE).take(10_000).collect();
+        prop_assert!(ids.iter().all(|&id| range.contains(id) && rule.matches(id, day_two::BASE)));
+        prop_assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        // A few thousand IDs this size cannot overflow a u128; more can.
+        match range.invalid_totals(&rule, day_two::BASE) {
+            Some(totals) if ids.len() < 10_000 => {
+                prop_assert_eq!(totals.count, ids.len() as u128);
+                prop_assert_eq!(totals.sum, ids.iter().sum::<u128>());
+            }
+            Some(_) => {}
+            None => prop_assert_eq!(ids.len(), 10_000),
+        }
+    }
+}
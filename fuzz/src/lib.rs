@@ -0,0 +1,19 @@
+//! Shared driver for the fuzz targets in `fuzz_targets/`.
+
+use aoc_common::Solution;
+
+/// Feed arbitrary bytes through `S`'s parse and each of its parts, the same
+/// path `aoc run` takes. Errors are expected; panics, hangs and crashes are
+/// what the fuzzer is looking for.
+pub fn solve<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(parsed) = S::parse(input) else {
+        return;
+    };
+    let _ = S::part_one(&parsed);
+    if S::HAS_PART_TWO {
+        let _ = S::part_two(&parsed);
+    }
+}
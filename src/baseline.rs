@@ -0,0 +1,144 @@
+//! Comparing Criterion runs of the `day_NN` benchmark groups against a saved baseline.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use aoc_common::Phase;
+use serde::Deserialize;
+
+/// The name Criterion stores the most recent run under.
+pub const LATEST: &str = "new";
+
+#[derive(Deserialize)]
+struct Estimates {
+    median: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    /// Nanoseconds per iteration.
+    point_estimate: f64,
+}
+
+/// Where `cargo bench` writes Criterion's results for this workspace.
+pub fn criterion_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("criterion")
+}
+
+/// Median time of one phase of `day` in the run saved as `name`, or `None`
+/// if that run did not measure it.
+pub fn phase_median(dir: &Path, day: u8, phase: Phase, name: &str) -> Result<Option<Duration>> {
+    let path = dir
+        .join(format!("day_{:02}", day))
+        .join(phase.name())
+        .join(name)
+        .join("estimates.json");
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read '{}'", path.display()))?;
+    let estimates: Estimates = serde_json::from_str(&text)
+        .with_context(|| format!("invalid Criterion estimates '{}'", path.display()))?;
+    Ok(Some(Duration::from_secs_f64(
+        estimates.median.point_estimate.max(0.0) / 1e9,
+    )))
+}
+
+/// One day's median run time in the baseline and in the current run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Change of the current median relative to the baseline, in percent;
+    /// positive means slower.
+    pub fn change_percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    pub fn regressed(&self, threshold_percent: f64) -> bool {
+        self.change_percent() > threshold_percent
+    }
+}
+
+/// Compare `day` between the runs saved as `baseline` and `current`.
+///
+/// A day's time is the sum of its phase medians. Only phases measured in
+/// both runs count, so a run filtered down to some phases still compares
+/// like with like. Returns `None` if the runs share no phase of the day.
+pub fn compare_day(
+    dir: &Path,
+    day: u8,
+    baseline: &str,
+    current: &str,
+) -> Result<Option<Comparison>> {
+    let mut comparison: Option<Comparison> = None;
+    for phase in Phase::ALL {
+        let before = phase_median(dir, day, phase, baseline)?;
+        let after = phase_median(dir, day, phase, current)?;
+        if let (Some(before), Some(after)) = (before, after) {
+            let c = comparison.get_or_insert(Comparison {
+                day,
+                baseline: Duration::ZERO,
+                current: Duration::ZERO,
+            });
+            c.baseline += before;
+            c.current += after;
+        }
+    }
+    Ok(comparison)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_median(dir: &Path, day: u8, phase: Phase, name: &str, ns: f64) {
+        let run = dir
+            .join(format!("day_{:02}", day))
+            .join(phase.name())
+            .join(name);
+        fs::create_dir_all(&run).unwrap();
+        let json =
+            format!(r#"{{"mean":{{"point_estimate":{ns}}},"median":{{"point_estimate":{ns}}}}}"#);
+        fs::write(run.join("estimates.json"), json).unwrap();
+    }
+
+    #[test]
+    fn compares_phases_present_in_both_runs() {
+        let dir = std::env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        write_median(&dir, 8, Phase::Parse, "main", 40_000_000.0);
+        write_median(&dir, 8, Phase::PartOne, "main", 10_000_000.0);
+        write_median(&dir, 8, Phase::PartTwo, "main", 5_000_000.0);
+        write_median(&dir, 8, Phase::Parse, LATEST, 46_000_000.0);
+        write_median(&dir, 8, Phase::PartOne, LATEST, 10_000_000.0);
+
+        let c = compare_day(&dir, 8, "main", LATEST).unwrap().unwrap();
+        assert_eq!(c.baseline, Duration::from_millis(50));
+        assert_eq!(c.current, Duration::from_millis(56));
+        assert!((c.change_percent() - 12.0).abs() < 1e-9);
+        assert!(c.regressed(10.0));
+        assert!(!c.regressed(15.0));
+
+        assert_eq!(compare_day(&dir, 9, "main", LATEST).unwrap(), None);
+
+        fs::write(dir.join("day_08/parse/main/estimates.json"), "not json").unwrap();
+        assert!(compare_day(&dir, 8, "main", LATEST).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
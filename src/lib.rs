@@ -0,0 +1,152 @@
+//! Registry of every day's solver, shared by the workspace tooling.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use aoc_common::{Answers, Registry};
+
+pub mod baseline;
+
+/// Crate directory for each day, indexed by day number minus one.
+const DAY_DIRS: [&str; 12] = [
+    "day_one",
+    "day_two",
+    "day_three",
+    "day_four",
+    "day_five",
+    "day_six",
+    "day_seven",
+    "day_eight",
+    "day_nine",
+    "day_ten",
+    "day_eleven",
+    "day_twelve",
+];
+
+/// Build a [`Registry`] holding all twelve days.
+pub fn registry() -> Registry {
+    Registry::new()
+        .with::<day_one::DayOne>()
+        .with::<day_two::DayTwo>()
+        .with::<day_three::DayThree>()
+        .with::<day_four::DayFour>()
+        .with::<day_five::DayFive>()
+        .with::<day_six::DaySix>()
+        .with::<day_seven::DaySeven>()
+        .with::<day_eight::DayEight>()
+        .with::<day_nine::DayNine>()
+        .with::<day_ten::DayTen>()
+        .with::<day_eleven::DayEleven>()
+        .with::<day_twelve::DayTwelve>()
+}
+
+/// Directory of `day`'s crate inside the workspace, independent of the current directory.
+pub fn day_dir(day: u8) -> Option<PathBuf> {
+    let dir = DAY_DIRS.get(usize::from(day).checked_sub(1)?)?;
+    Some(Path::new(env!("CARGO_MANIFEST_DIR")).join(dir))
+}
+
+/// The puzzle input bundled with `day`'s crate.
+pub fn input_path(day: u8) -> Option<PathBuf> {
+    day_dir(day).map(|dir| dir.join("input.txt"))
+}
+
+/// The accepted answers recorded next to `day`'s bundled input.
+pub fn answers_path(day: u8) -> Option<PathBuf> {
+    day_dir(day).map(|dir| dir.join("answers.txt"))
+}
+
+/// Load `day`'s recorded answers, or `None` if it has no answers file yet.
+pub fn read_answers(day: u8) -> Result<Option<Answers>> {
+    let Some(path) = answers_path(day).filter(|p| p.is_file()) else {
+        return Ok(None);
+    };
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read '{}'", path.display()))?;
+    let answers = text
+        .parse()
+        .with_context(|| format!("invalid answers file '{}'", path.display()))?;
+    Ok(Some(answers))
+}
+
+/// Read puzzle input from `path`, where `-` means standard input.
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("failed to read input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("failed to read '{}'", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Generator;
+
+    #[test]
+    fn registers_every_day() {
+        let registry = registry();
+        assert_eq!(
+            registry.days().collect::<Vec<_>>(),
+            (1..=12).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn bundled_inputs_exist() {
+        for day in 1..=12 {
+            assert!(input_path(day).unwrap().is_file(), "day {day}");
+        }
+        assert!(input_path(0).is_none());
+        assert!(input_path(13).is_none());
+    }
+
+    fn solves_generated_input<S: Generator>() {
+        let size = S::SIZES[0];
+        let input = S::generate(size, 1);
+        assert_eq!(input, S::generate(size, 1), "day {}", S::DAY);
+        assert_ne!(input, S::generate(size, 2), "day {}", S::DAY);
+
+        let parsed = S::parse(&input).unwrap_or_else(|e| panic!("day {}: {:#}", S::DAY, e));
+        S::part_one(&parsed).unwrap_or_else(|e| panic!("day {} part 1: {:#}", S::DAY, e));
+        if S::HAS_PART_TWO {
+            S::part_two(&parsed).unwrap_or_else(|e| panic!("day {} part 2: {:#}", S::DAY, e));
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_valid() {
+        solves_generated_input::<day_one::DayOne>();
+        solves_generated_input::<day_two::DayTwo>();
+        solves_generated_input::<day_three::DayThree>();
+        solves_generated_input::<day_four::DayFour>();
+        solves_generated_input::<day_five::DayFive>();
+        solves_generated_input::<day_six::DaySix>();
+        solves_generated_input::<day_seven::DaySeven>();
+        solves_generated_input::<day_eight::DayEight>();
+        solves_generated_input::<day_nine::DayNine>();
+        solves_generated_input::<day_ten::DayTen>();
+        solves_generated_input::<day_eleven::DayEleven>();
+        solves_generated_input::<day_twelve::DayTwelve>();
+    }
+
+    #[test]
+    fn recorded_answers_cover_every_part() {
+        let registry = registry();
+        for solver in registry.iter() {
+            let day = solver.day();
+            let answers = read_answers(day).unwrap().expect("answers.txt");
+            assert_eq!(
+                answers.parts().collect::<Vec<_>>(),
+                solver.parts(),
+                "day {day}"
+            );
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use aoc_common::{DayResult, Part, Registry, Timings};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day when --day is omitted.
+    Run(RunArgs),
+    /// Re-solve days and compare against the answers recorded in each day's answers.txt.
+    Verify(VerifyArgs),
+    /// Compare the latest `cargo bench` run against a saved Criterion baseline.
+    Compare(CompareArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve (1-12). Solves every day when omitted.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
+    day: Option<u8>,
+
+    /// Only solve this part (1 or 2).
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Input file, or `-` for stdin. Defaults to the day's bundled input.txt.
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// How to print results.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// In text output, also print how long each day spent parsing and on each part.
+    #[arg(short, long)]
+    time: bool,
+
+    /// Solve every day concurrently. Text output becomes a summary table with
+    /// each day's answers and timings and the total wall time.
+    #[arg(short = 'j', long, conflicts_with = "day")]
+    parallel: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One `Day NN part P: answer` line per part; errors go to stderr.
+    Text,
+    /// A JSON array of results on stdout.
+    Json,
+    /// CSV with a header row on stdout.
+    Csv,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to verify (1-12). Verifies every day when omitted.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// Baseline saved with `cargo bench -- --save-baseline <NAME>`.
+    #[arg(short, long)]
+    baseline: String,
+
+    /// Run to check against the baseline. Defaults to the latest run.
+    #[arg(short, long, default_value = aoc_benchmark::baseline::LATEST)]
+    current: String,
+
+    /// Fail if a day's median run time grows by more than this many percent.
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Day to compare (1-12). Compares every day when omitted.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
+    day: Option<u8>,
+
+    /// Criterion output directory. Defaults to the workspace's target/criterion.
+    #[arg(long)]
+    criterion_dir: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Compare(args) => compare(&args),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solve the requested days and print a result for every part in the chosen format.
+/// Returns `false` if any part failed.
+fn run(args: &RunArgs) -> Result<bool> {
+    let registry = aoc_benchmark::registry();
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => registry.days().collect(),
+    };
+    let solve = |day| solve_day(&registry, args, day);
+
+    let mut results = Vec::new();
+    if args.parallel {
+        let start = Instant::now();
+        let by_day = days
+            .par_iter()
+            .map(|&day| solve(day))
+            .collect::<Result<Vec<_>>>()?;
+        let wall = start.elapsed();
+        if args.format == Format::Text {
+            print_summary(&by_day, wall);
+        }
+        results.extend(by_day.into_iter().flatten());
+    } else {
+        for day in days {
+            let day_results = solve(day)?;
+            if args.format == Format::Text && !day_results.is_empty() {
+                day_results.iter().for_each(print_text);
+                if args.time {
+                    println!(
+                        "Day {:02} time: {}",
+                        day,
+                        Timings::from_results(&day_results)
+                    );
+                }
+            }
+            results.extend(day_results);
+        }
+    }
+
+    match args.format {
+        Format::Text => {}
+        Format::Json => println!("{}", aoc_common::results_to_json(&results)?),
+        Format::Csv => aoc_common::write_results_csv(&results, io::stdout().lock())?,
+    }
+
+    Ok(results.iter().all(DayResult::is_ok))
+}
+
+/// Solve the parts of `day` that `args` asks for. Days skipped by the part
+/// filter give no results; failures are reported as failed results.
+fn solve_day(registry: &Registry, args: &RunArgs, day: u8) -> Result<Vec<DayResult>> {
+    let solver = registry
+        .get(day)
+        .with_context(|| format!("no solver registered for day {}", day))?;
+
+    let parts: Vec<Part> = match args.part {
+        // A single requested day reports a missing part as an error;
+        // a run over every day just skips days that lack it.
+        Some(part) if args.day.is_some() || solver.parts().contains(&part) => vec![part],
+        Some(_) => return Ok(Vec::new()),
+        None => solver.parts().to_vec(),
+    };
+
+    let path = match &args.input {
+        Some(path) => path.clone(),
+        None => aoc_benchmark::input_path(day)
+            .with_context(|| format!("no bundled input for day {}", day))?,
+    };
+    Ok(match aoc_benchmark::read_input(&path) {
+        Ok(input) => solver.run(&input, &parts),
+        Err(e) => parts
+            .iter()
+            .map(|&part| DayResult::failed(day, part, &e, Duration::ZERO))
+            .collect(),
+    })
+}
+
+/// Print one table row per day with its answers and timings, marking the
+/// slowest day, then the wall time of the whole run. Errors go to stderr.
+fn print_summary(by_day: &[Vec<DayResult>], wall: Duration) {
+    let answer = |results: &[DayResult], part: Part| {
+        results
+            .iter()
+            .find(|r| r.part == part)
+            .map_or("-", |r| r.answer.as_deref().unwrap_or("error"))
+            .to_string()
+    };
+    let time = |t: Option<Duration>| t.map_or("-".to_string(), |t| format!("{:.2?}", t));
+
+    let header = [
+        "Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total",
+    ];
+    let mut rows = Vec::new();
+    let mut totals = Vec::new();
+    for results in by_day.iter().filter(|r| !r.is_empty()) {
+        let timings = Timings::from_results(results);
+        totals.push(timings.total());
+        rows.push([
+            format!("{:02}", results[0].day),
+            answer(results, Part::One),
+            answer(results, Part::Two),
+            time(Some(timings.parse)),
+            time(timings.part_one),
+            time(timings.part_two),
+            time(Some(timings.total())),
+        ]);
+    }
+    let slowest = totals
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, total)| total)
+        .map(|(i, _)| i);
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: &[String]| {
+        let mut out = String::new();
+        for (i, (cell, width)) in cells.iter().zip(widths).enumerate() {
+            // Answers are left aligned, numbers and times right aligned.
+            match i {
+                1 | 2 => out.push_str(&format!("{:<width$}  ", cell)),
+                _ => out.push_str(&format!("{:>width$}  ", cell)),
+            }
+        }
+        out.trim_end().to_string()
+    };
+
+    println!("{}", line(&header.map(String::from)));
+    for (i, row) in rows.iter().enumerate() {
+        let marker = if Some(i) == slowest {
+            "  <- slowest"
+        } else {
+            ""
+        };
+        println!("{}{}", line(row), marker);
+    }
+    println!(
+        "Wall time: {:.2?} (sum of day totals {:.2?})",
+        wall,
+        totals.iter().sum::<Duration>()
+    );
+
+    for result in by_day.iter().flatten() {
+        if let Some(error) = &result.error {
+            eprintln!(
+                "Day {:02} part {}: error: {}",
+                result.day, result.part, error
+            );
+        }
+    }
+}
+
+fn print_text(result: &DayResult) {
+    match (&result.answer, &result.error) {
+        (Some(answer), _) => println!("Day {:02} part {}: {}", result.day, result.part, answer),
+        (None, error) => eprintln!(
+            "Day {:02} part {}: error: {}",
+            result.day,
+            result.part,
+            error.as_deref().unwrap_or("no answer")
+        ),
+    }
+}
+
+/// Solve each day's bundled input and compare every part against its
+/// recorded answer. Returns `false` on any mismatch or failure.
+fn verify(args: &VerifyArgs) -> Result<bool> {
+    let registry = aoc_benchmark::registry();
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => registry.days().collect(),
+    };
+
+    let mut checked = 0;
+    let mut failed = 0;
+    for day in days {
+        let solver = registry
+            .get(day)
+            .with_context(|| format!("no solver registered for day {}", day))?;
+
+        let answers = match aoc_benchmark::read_answers(day) {
+            Ok(Some(answers)) => answers,
+            Ok(None) => {
+                println!("Day {:02}: no recorded answers", day);
+                continue;
+            }
+            Err(e) => {
+                eprintln!("Day {:02}: error: {:#}", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+        for part in solver.parts() {
+            if answers.get(*part).is_none() {
+                println!("Day {:02} part {}: no recorded answer", day, part);
+            }
+        }
+
+        let parts: Vec<Part> = answers.parts().collect();
+        checked += parts.len();
+        let path = aoc_benchmark::input_path(day)
+            .with_context(|| format!("no bundled input for day {}", day))?;
+        let result =
+            aoc_benchmark::read_input(&path).and_then(|input| solver.solve(&input, &parts));
+
+        match result {
+            Ok(got) => {
+                for (part, got) in parts.iter().zip(got) {
+                    let expected = answers.get(*part).unwrap_or_default();
+                    if got == expected {
+                        println!("Day {:02} part {}: ok", day, part);
+                    } else {
+                        println!(
+                            "Day {:02} part {}: MISMATCH expected {}, got {}",
+                            day, part, expected, got
+                        );
+                        failed += 1;
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Day {:02}: error: {:#}", day, e);
+                failed += parts.len();
+            }
+        }
+    }
+
+    println!(
+        "{} of {} recorded answers match",
+        checked.saturating_sub(failed),
+        checked
+    );
+    Ok(failed == 0)
+}
+
+/// Compare each day's median run time in the current Criterion run against the
+/// baseline. Returns `false` if any day regressed past the threshold.
+fn compare(args: &CompareArgs) -> Result<bool> {
+    let dir = args
+        .criterion_dir
+        .clone()
+        .unwrap_or_else(aoc_benchmark::baseline::criterion_dir);
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => aoc_benchmark::registry().days().collect(),
+    };
+
+    let mut compared = 0;
+    let mut regressed = 0;
+    for day in days {
+        let comparison =
+            aoc_benchmark::baseline::compare_day(&dir, day, &args.baseline, &args.current)?;
+        let Some(c) = comparison else {
+            println!(
+                "Day {:02}: not measured in both '{}' and '{}'",
+                day, args.baseline, args.current
+            );
+            continue;
+        };
+
+        compared += 1;
+        let verdict = if c.regressed(args.threshold) {
+            regressed += 1;
+            "REGRESSED"
+        } else {
+            "ok"
+        };
+        println!(
+            "Day {:02}: {:.2?} -> {:.2?} ({:+.1}%) {}",
+            day,
+            c.baseline,
+            c.current,
+            c.change_percent(),
+            verdict
+        );
+    }
+
+    if compared == 0 {
+        anyhow::bail!(
+            "no benchmark results to compare in '{}'; save a baseline with \
+             `cargo bench -- --save-baseline {}` first",
+            dir.display(),
+            args.baseline
+        );
+    }
+    println!(
+        "{} of {} days regressed by more than {}%",
+        regressed, compared, args.threshold
+    );
+    Ok(regressed == 0)
+}